@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ggez::GameResult;
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+
+pub const BURST_WPM: f32 = 100.0;
+// the burst speed is measured over this many words in a row
+pub const BURST_WORD_COUNT: usize = 5;
+pub const FLAWLESS_WORD_COUNT: u32 = 50;
+pub const COLLECTED_POWER_UPS: u32 = 10;
+pub const HIGH_SCORE: u32 = 1000;
+pub const DAYS_IN_A_ROW: u32 = 7;
+pub const ACHIEVEMENT_TOAST_DURATION: f32 = 3.0;
+
+// letters per word when converting typed letters into words per minute
+const LETTERS_PER_WORD: f32 = 5.0;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    WpmBurst,
+    FlawlessGame,
+    PowerUpCollector,
+    HighScore,
+    DailyPlayer,
+}
+
+impl Achievement {
+    pub const ALL: [Achievement; 5] = [
+        Achievement::WpmBurst,
+        Achievement::FlawlessGame,
+        Achievement::PowerUpCollector,
+        Achievement::HighScore,
+        Achievement::DailyPlayer,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            Achievement::WpmBurst => String::from("SPEED DEMON"),
+            Achievement::FlawlessGame => String::from("FLAWLESS"),
+            Achievement::PowerUpCollector => String::from("COLLECTOR"),
+            Achievement::HighScore => String::from("HIGH SCORER"),
+            Achievement::DailyPlayer => String::from("REGULAR"),
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            Achievement::WpmBurst => format!("TYPE A {} WPM BURST", BURST_WPM),
+            Achievement::FlawlessGame => {
+                format!("{} WORDS WITHOUT A MISTAKE", FLAWLESS_WORD_COUNT)
+            }
+            Achievement::PowerUpCollector => {
+                format!("COLLECT {} POWER-UPS IN ONE RUN", COLLECTED_POWER_UPS)
+            }
+            Achievement::HighScore => format!("REACH A SCORE OF {}", HIGH_SCORE),
+            Achievement::DailyPlayer => format!("PLAY {} DAYS IN A ROW", DAYS_IN_A_ROW),
+        }
+    }
+}
+
+// what happened during the current run
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RunStats {
+    pub elapsed_time: f32,
+    pub completed_words: u32,
+    pub typed_letters: u32,
+    // wrong letters and missed words
+    pub mistakes: u32,
+    pub collected_power_ups: u32,
+    pub score: u32,
+    // when the latest words were finished and how many letters they had
+    pub recent_words: VecDeque<(f32, usize)>,
+}
+
+impl RunStats {
+    pub fn register_completed_word(&mut self, letters_count: usize) {
+        self.completed_words += 1;
+        self.typed_letters += letters_count as u32;
+        self.recent_words
+            .push_back((self.elapsed_time, letters_count));
+        if self.recent_words.len() > BURST_WORD_COUNT {
+            self.recent_words.pop_front();
+        }
+    }
+
+    // the share of correct letters, a run without any letters counts as accurate
+    pub fn get_accuracy(&self) -> f32 {
+        if self.typed_letters + self.mistakes == 0 {
+            return 1.0;
+        }
+        self.typed_letters as f32 / (self.typed_letters + self.mistakes) as f32
+    }
+
+    // the typing speed over the latest words, the first one only marks the start
+    pub fn get_burst_wpm(&self) -> f32 {
+        let (Some((start, _)), Some((end, _))) =
+            (self.recent_words.front(), self.recent_words.back())
+        else {
+            return 0.0;
+        };
+        if self.recent_words.len() < BURST_WORD_COUNT || end <= start {
+            return 0.0;
+        }
+        let letters_count: usize = self
+            .recent_words
+            .iter()
+            .skip(1)
+            .map(|(_, count)| count)
+            .sum();
+        letters_count as f32 / LETTERS_PER_WORD / ((end - start) / 60.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Achievements {
+    pub unlocked: Vec<Achievement>,
+    // days since the unix epoch
+    pub last_played_day: Option<u64>,
+    pub days_in_a_row: u32,
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Achievements {
+    pub fn load(path: &Path) -> Achievements {
+        let mut achievements: Achievements = storage::load_toml_or_default(path, "achievements");
+        achievements.path = Some(path.to_path_buf());
+        achievements
+    }
+
+    pub fn save(&self) -> GameResult {
+        match &self.path {
+            Some(path) => storage::save_toml(path, self),
+            None => Ok(()),
+        }
+    }
+
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    // counts the days played in a row, playing twice on the same day counts once
+    pub fn register_play_day(&mut self, day: u64) {
+        self.days_in_a_row = match self.last_played_day {
+            Some(last_day) if last_day == day => self.days_in_a_row,
+            Some(last_day) if last_day + 1 == day => self.days_in_a_row + 1,
+            _ => 1,
+        };
+        self.last_played_day = Some(day);
+    }
+
+    // unlocks every achievement the stats earn and returns the new ones
+    pub fn check(&mut self, stats: &RunStats) -> Vec<Achievement> {
+        let earned: Vec<Achievement> = Achievement::ALL
+            .into_iter()
+            .filter(|achievement| !self.is_unlocked(*achievement))
+            .filter(|achievement| match achievement {
+                Achievement::WpmBurst => stats.get_burst_wpm() >= BURST_WPM,
+                Achievement::FlawlessGame => {
+                    stats.completed_words >= FLAWLESS_WORD_COUNT && stats.mistakes == 0
+                }
+                Achievement::PowerUpCollector => stats.collected_power_ups >= COLLECTED_POWER_UPS,
+                Achievement::HighScore => stats.score >= HIGH_SCORE,
+                Achievement::DailyPlayer => self.days_in_a_row >= DAYS_IN_A_ROW,
+            })
+            .collect();
+        if !earned.is_empty() {
+            self.unlocked.extend(&earned);
+            if let Err(err) = self.save() {
+                eprintln!("could not save the achievements: {}", err);
+            }
+        }
+        earned
+    }
+}
+
+pub fn get_current_day() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / SECONDS_PER_DAY)
+}
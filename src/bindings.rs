@@ -0,0 +1,331 @@
+use std::collections::{BTreeMap, HashMap};
+
+use ggez::input::keyboard::KeyCode;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    MenuUp,
+    MenuDown,
+    Select,
+    Back,
+    // opens the word scores on the results screen
+    Details,
+    Pause,
+}
+
+// actions of different contexts never fire on the same key press, so they may share keys
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ActionContext {
+    Menu,
+    Game,
+}
+
+impl Action {
+    pub const ALL: [Action; 6] = [
+        Action::MenuUp,
+        Action::MenuDown,
+        Action::Select,
+        Action::Back,
+        Action::Details,
+        Action::Pause,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            Action::MenuUp => String::from("UP"),
+            Action::MenuDown => String::from("DOWN"),
+            Action::Select => String::from("SELECT"),
+            Action::Back => String::from("BACK"),
+            Action::Details => String::from("DETAILS"),
+            Action::Pause => String::from("PAUSE"),
+        }
+    }
+
+    // the key of the action in the settings file
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Action::MenuUp => "menu_up",
+            Action::MenuDown => "menu_down",
+            Action::Select => "select",
+            Action::Back => "back",
+            Action::Details => "details",
+            Action::Pause => "pause",
+        }
+    }
+
+    pub fn get_context(&self) -> ActionContext {
+        match self {
+            Action::Pause => ActionContext::Game,
+            _ => ActionContext::Menu,
+        }
+    }
+
+    fn get_default_keys(&self) -> Vec<KeyCode> {
+        match self {
+            Action::MenuUp => vec![KeyCode::Up],
+            Action::MenuDown => vec![KeyCode::Down],
+            Action::Select => vec![KeyCode::Space, KeyCode::Return],
+            Action::Back => vec![KeyCode::Left, KeyCode::Escape],
+            Action::Details => vec![KeyCode::Right],
+            Action::Pause => vec![KeyCode::Escape],
+        }
+    }
+}
+
+// only these keys can be saved, the letters are listed separately since they type words
+const CONTROL_KEYS: [KeyCode; 37] = [
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::Space,
+    KeyCode::Return,
+    KeyCode::Escape,
+    KeyCode::Tab,
+    KeyCode::Back,
+    KeyCode::Delete,
+    KeyCode::Insert,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Key0,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+];
+
+const LETTER_KEYS: [KeyCode; 26] = [
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+];
+
+// keys are stored under their ggez names, like "Return" or "F5"
+pub fn get_key_name(key: KeyCode) -> String {
+    format!("{:?}", key)
+}
+
+pub fn get_key_by_name(name: &str) -> Option<KeyCode> {
+    CONTROL_KEYS
+        .iter()
+        .chain(LETTER_KEYS.iter())
+        .find(|key| get_key_name(**key) == name)
+        .copied()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BindingError {
+    // the key already belongs to another action of the same context
+    Conflict(Action),
+    // letters are needed for typing while the game runs
+    TypingKey,
+    Unsupported,
+}
+
+impl BindingError {
+    pub fn get_message(&self, key: KeyCode) -> String {
+        let key_name = get_key_name(key).to_uppercase();
+        match self {
+            BindingError::Conflict(action) => {
+                format!("{} IS ALREADY USED FOR {}", key_name, action.get_label())
+            }
+            BindingError::TypingKey => format!("{} IS NEEDED FOR TYPING", key_name),
+            BindingError::Unsupported => format!("{} CANNOT BE BOUND", key_name),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bindings {
+    keys: HashMap<Action, Vec<KeyCode>>,
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        Self {
+            keys: Action::ALL
+                .iter()
+                .map(|action| (*action, action.get_default_keys()))
+                .collect(),
+        }
+    }
+}
+
+impl Bindings {
+    pub fn get_keys(&self, action: Action) -> &[KeyCode] {
+        self.keys.get(&action).map_or(&[], |keys| keys.as_slice())
+    }
+
+    pub fn get_action(&self, key: KeyCode, context: ActionContext) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .filter(|action| action.get_context() == context)
+            .find(|action| self.get_keys(*action).contains(&key))
+    }
+
+    pub fn get_menu_action(&self, key: KeyCode) -> Option<Action> {
+        self.get_action(key, ActionContext::Menu)
+    }
+
+    pub fn get_game_action(&self, key: KeyCode) -> Option<Action> {
+        self.get_action(key, ActionContext::Game)
+    }
+
+    pub fn find_conflict(&self, action: Action, key: KeyCode) -> Option<Action> {
+        self.get_action(key, action.get_context())
+            .filter(|bound_action| *bound_action != action)
+    }
+
+    fn check_key(&self, action: Action, key: KeyCode) -> Result<(), BindingError> {
+        if LETTER_KEYS.contains(&key) && action.get_context() == ActionContext::Game {
+            return Err(BindingError::TypingKey);
+        }
+        if get_key_by_name(&get_key_name(key)).is_none() {
+            return Err(BindingError::Unsupported);
+        }
+        if let Some(bound_action) = self.find_conflict(action, key) {
+            return Err(BindingError::Conflict(bound_action));
+        }
+        Ok(())
+    }
+
+    // the key replaces every key the action had before
+    pub fn bind(&mut self, action: Action, key: KeyCode) -> Result<(), BindingError> {
+        self.check_key(action, key)?;
+        self.keys.insert(action, vec![key]);
+        Ok(())
+    }
+
+    // an action needs at least one key and every key has to pass the checks of bind
+    pub fn is_valid(&self, action: Action) -> bool {
+        let keys = self.get_keys(action);
+        !keys.is_empty() && keys.iter().all(|key| self.check_key(action, *key).is_ok())
+    }
+
+    // a hand-edited file can hold keys bind would refuse, those actions get their default keys
+    // back and if that still leaves a conflict, every action does
+    fn reset_invalid_actions(&mut self) {
+        for action in Action::ALL {
+            if !self.is_valid(action) {
+                eprintln!(
+                    "the keys bound to {} are not usable, using the default keys",
+                    action.get_name()
+                );
+                self.keys.insert(action, action.get_default_keys());
+            }
+        }
+        if !Action::ALL.iter().all(|action| self.is_valid(*action)) {
+            eprintln!("the key bindings conflict with each other, using the default keys");
+            *self = Bindings::default();
+        }
+    }
+
+    // the keys of the action as shown in menus and hints, like "SPACE, RETURN"
+    pub fn get_keys_label(&self, action: Action) -> String {
+        let keys: Vec<String> = self
+            .get_keys(action)
+            .iter()
+            .map(|key| get_key_name(*key).to_uppercase())
+            .collect();
+        keys.join(", ")
+    }
+
+    pub fn get_option_label(&self, action: Action) -> String {
+        format!("{}: {}", action.get_label(), self.get_keys_label(action))
+    }
+}
+
+impl Serialize for Bindings {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let names: BTreeMap<String, Vec<String>> = Action::ALL
+            .iter()
+            .map(|action| {
+                let key_names = self
+                    .get_keys(*action)
+                    .iter()
+                    .map(|key| get_key_name(*key))
+                    .collect();
+                (action.get_name().to_string(), key_names)
+            })
+            .collect();
+        names.serialize(serializer)
+    }
+}
+
+// actions missing from the file keep their default keys, and so do actions with a key that
+// is unknown or breaks the rules of bind, so one bad entry never costs the rest of the settings
+impl<'de> Deserialize<'de> for Bindings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names = HashMap::<String, Vec<String>>::deserialize(deserializer)?;
+        let mut bindings = Bindings::default();
+        for (action_name, key_names) in names {
+            let Some(action) = Action::ALL
+                .into_iter()
+                .find(|action| action.get_name() == action_name)
+            else {
+                eprintln!(
+                    "skipping the bindings of the unknown action {}",
+                    action_name
+                );
+                continue;
+            };
+            let keys: Option<Vec<KeyCode>> =
+                key_names.iter().map(|name| get_key_by_name(name)).collect();
+            match keys {
+                Some(keys) => {
+                    bindings.keys.insert(action, keys);
+                }
+                None => eprintln!(
+                    "the keys bound to {} include an unknown key, using the default keys",
+                    action_name
+                ),
+            }
+        }
+        bindings.reset_invalid_actions();
+        Ok(bindings)
+    }
+}
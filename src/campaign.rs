@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::PathBuf;
+
+use ggez::{Context, GameError, GameResult};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::SOURCE_WORDS;
+use crate::effects::{EffectTable, DOUBLE_SCORE_MULTIPLIER, HAZARD_SCORE_MULTIPLIER};
+use crate::motion::MotionPattern;
+use crate::rules::{default_max_lives, default_starting_lives, GameRules};
+use crate::scoring::WordScore;
+use crate::storage;
+use crate::streak::STREAK_MULTIPLIERS;
+use crate::word::{WordEffect, DEFAULT_EFFECT_CHANCE};
+
+pub const LEVELS_DIR: &str = "/levels";
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SpeedCurve {
+    pub initial: u32,
+    pub increase_per_word: u32,
+    #[serde(default)]
+    pub max: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SpawnCurve {
+    pub initial_interval: f32,
+    pub decay: f32,
+    pub min_interval: f32,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "WinConditionTable")]
+pub enum WinCondition {
+    CompleteWords(u32),
+    ReachScore(u32),
+}
+
+#[derive(Deserialize)]
+struct WinConditionTable {
+    complete_words: Option<u32>,
+    reach_score: Option<u32>,
+}
+
+impl TryFrom<WinConditionTable> for WinCondition {
+    type Error = String;
+
+    fn try_from(table: WinConditionTable) -> Result<Self, Self::Error> {
+        match (table.complete_words, table.reach_score) {
+            (Some(words), None) => Ok(WinCondition::CompleteWords(words)),
+            (None, Some(score)) => Ok(WinCondition::ReachScore(score)),
+            _ => Err(String::from(
+                "win must set exactly one of `complete_words` or `reach_score`",
+            )),
+        }
+    }
+}
+
+impl WinCondition {
+    pub fn is_met(&self, completed_words: u32, score: u32) -> bool {
+        match *self {
+            WinCondition::CompleteWords(target) => completed_words >= target,
+            WinCondition::ReachScore(target) => score >= target,
+        }
+    }
+}
+
+fn default_allowed_effects() -> Vec<WordEffect> {
+    Vec::from(WordEffect::ALL)
+}
+
+fn default_motion_patterns() -> Vec<MotionPattern> {
+    vec![MotionPattern::Linear]
+}
+
+fn default_effect_chance() -> u32 {
+    DEFAULT_EFFECT_CHANCE
+}
+
+fn default_word_score() -> u32 {
+    crate::rules::WORD_SCORE
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Level {
+    pub name: String,
+    // an empty pool falls back to the default source words
+    #[serde(default)]
+    pub words: Vec<String>,
+    #[serde(default = "default_allowed_effects")]
+    pub allowed_effects: Vec<WordEffect>,
+    #[serde(default = "default_motion_patterns")]
+    pub motion_patterns: Vec<MotionPattern>,
+    // overrides the default duration of timed effects, in seconds
+    #[serde(default, deserialize_with = "storage::deserialize_enum_map")]
+    pub effect_durations: HashMap<WordEffect, f32>,
+    #[serde(default = "default_effect_chance")]
+    pub effect_chance: u32,
+    #[serde(default)]
+    pub hazard_chance: u32,
+    #[serde(default = "default_word_score")]
+    pub word_score: u32,
+    #[serde(default = "default_starting_lives")]
+    pub starting_lives: u32,
+    #[serde(default = "default_max_lives")]
+    pub max_lives: u32,
+    pub speed: SpeedCurve,
+    pub spawn: SpawnCurve,
+    pub win: WinCondition,
+    pub stars: [u32; 3],
+}
+
+impl Level {
+    pub fn from_toml_str(contents: &str) -> GameResult<Level> {
+        toml::from_str(contents)
+            .map_err(|err| GameError::ResourceLoadError(format!("invalid level file: {}", err)))
+    }
+
+    pub fn get_rules(&self) -> GameRules {
+        GameRules {
+            starting_lives: self.starting_lives.min(self.max_lives),
+            max_lives: self.max_lives,
+            initial_game_speed: self.speed.initial,
+            speed_increase_per_word: self.speed.increase_per_word,
+            max_game_speed: self.speed.max,
+            initial_spawn_interval: self.spawn.initial_interval,
+            spawn_interval_decay: self.spawn.decay,
+            min_spawn_interval: self.spawn.min_interval,
+            effect_chance: self.effect_chance,
+            hazard_chance: self.hazard_chance,
+            word_score: self.word_score,
+        }
+    }
+
+    // the most a single word can be worth: the best word in the pool, typed instantly at the
+    // bottom of the screen with every multiplier the level allows
+    pub fn get_max_word_score(&self, effect_table: &EffectTable) -> u32 {
+        let best_points = if self.words.is_empty() {
+            SOURCE_WORDS
+                .iter()
+                .map(|word| self.get_max_points(word))
+                .max()
+        } else {
+            self.words
+                .iter()
+                .map(|word| self.get_max_points(word))
+                .max()
+        };
+        let mut multiplier = STREAK_MULTIPLIERS
+            .iter()
+            .map(|&(_, multiplier)| multiplier)
+            .max()
+            .unwrap_or(1);
+        if self.allowed_effects.contains(&WordEffect::DoubleScore) {
+            multiplier *= effect_table.get_value(WordEffect::DoubleScore, DOUBLE_SCORE_MULTIPLIER);
+        }
+        if self.hazard_chance > 0 && self.allowed_effects.iter().any(|effect| effect.is_hazard()) {
+            multiplier *= HAZARD_SCORE_MULTIPLIER;
+        }
+        best_points.unwrap_or(0) * multiplier
+    }
+
+    fn get_max_points(&self, word: &str) -> u32 {
+        WordScore::new(word, self.word_score, 0.0, 1.0).get_points()
+    }
+
+    // an upper bound on the score the level can end with; a score goal ends the level with the
+    // word that reaches it, so it can be overshot by one word at most
+    pub fn get_max_score(&self, effect_table: &EffectTable) -> u32 {
+        match self.win {
+            WinCondition::CompleteWords(target) => target * self.get_max_word_score(effect_table),
+            WinCondition::ReachScore(target) => {
+                target.saturating_sub(1) + self.get_max_word_score(effect_table)
+            }
+        }
+    }
+
+    pub fn count_stars(&self, score: u32) -> u32 {
+        self.stars
+            .iter()
+            .filter(|&&threshold| score >= threshold)
+            .count() as u32
+    }
+}
+
+pub fn load_levels(ctx: &Context) -> GameResult<Vec<Level>> {
+    let mut paths: Vec<PathBuf> = ctx
+        .fs
+        .read_dir(LEVELS_DIR)?
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    let mut levels = Vec::new();
+    for path in paths {
+        let mut contents = String::new();
+        ctx.fs.open(&path)?.read_to_string(&mut contents)?;
+        let level = Level::from_toml_str(&contents)
+            .map_err(|err| GameError::ResourceLoadError(format!("{}: {}", path.display(), err)))?;
+        levels.push(level);
+    }
+    Ok(levels)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CampaignProgress {
+    pub unlocked_levels: usize,
+    pub best_stars: Vec<u32>,
+}
+
+impl Default for CampaignProgress {
+    fn default() -> Self {
+        Self {
+            unlocked_levels: 1,
+            best_stars: Vec::new(),
+        }
+    }
+}
+
+impl CampaignProgress {
+    pub fn get_stars(&self, level_index: usize) -> u32 {
+        self.best_stars.get(level_index).copied().unwrap_or(0)
+    }
+
+    pub fn record_level_completion(&mut self, level_index: usize, stars: u32) {
+        if self.best_stars.len() <= level_index {
+            self.best_stars.resize(level_index + 1, 0);
+        }
+        self.best_stars[level_index] = self.best_stars[level_index].max(stars);
+        self.unlocked_levels = self.unlocked_levels.max(level_index + 2);
+    }
+}
+
+pub struct Campaign {
+    pub levels: Vec<Level>,
+    pub progress: CampaignProgress,
+    pub progress_path: Option<PathBuf>,
+}
+
+impl Campaign {
+    pub fn new(levels: Vec<Level>) -> Self {
+        Self {
+            levels,
+            progress: CampaignProgress::default(),
+            progress_path: None,
+        }
+    }
+
+    pub fn with_saved_progress(levels: Vec<Level>, progress_path: PathBuf) -> Self {
+        let progress = storage::load_toml_or_default(&progress_path, "campaign progress");
+        Self {
+            levels,
+            progress,
+            progress_path: Some(progress_path),
+        }
+    }
+
+    pub fn is_unlocked(&self, level_index: usize) -> bool {
+        level_index < self.progress.unlocked_levels.min(self.levels.len())
+    }
+
+    pub fn get_level_label(&self, level_index: usize) -> String {
+        if self.is_unlocked(level_index) {
+            format!(
+                "{}. {} ({}/3)",
+                level_index + 1,
+                self.levels[level_index].name.to_uppercase(),
+                self.progress.get_stars(level_index)
+            )
+        } else {
+            format!("{}. LOCKED", level_index + 1)
+        }
+    }
+
+    pub fn save_progress(&self) -> GameResult {
+        match &self.progress_path {
+            Some(path) => storage::save_toml(path, &self.progress),
+            None => Ok(()),
+        }
+    }
+}
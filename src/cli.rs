@@ -0,0 +1,121 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use crate::game::GameMode;
+use crate::preset::Difficulty;
+
+pub const MIN_WINDOW_WIDTH: f32 = 640.0;
+pub const MIN_WINDOW_HEIGHT: f32 = 480.0;
+
+/// A typing game where falling words have to be typed before they reach the bottom.
+#[derive(Parser, Debug, PartialEq)]
+#[command(name = "typing-tutor", version)]
+pub struct Cli {
+    /// Game mode: endless, waves, campaign or blind
+    #[arg(long, value_parser = parse_mode)]
+    pub mode: Option<GameMode>,
+
+    /// Word list with one word per line, replaces the built-in words
+    #[arg(long, value_name = "FILE")]
+    pub words: Option<PathBuf>,
+
+    /// Seed for spawning, effects and motion, the same seed repeats the same game
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Difficulty preset: easy, normal, hard, insane or custom
+    #[arg(long, value_parser = parse_difficulty)]
+    pub difficulty: Option<Difficulty>,
+
+    /// Start in fullscreen
+    #[arg(long)]
+    pub fullscreen: bool,
+
+    /// Window size, for example 1280x720
+    #[arg(long, value_name = "WIDTHxHEIGHT", value_parser = parse_window_size)]
+    pub window_size: Option<(f32, f32)>,
+
+    /// Settings file to use instead of the one in the config directory
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Start a game right away instead of showing the main menu
+    #[arg(long)]
+    pub skip_menu: bool,
+
+    /// Stream the game to spectators
+    #[arg(long)]
+    pub spectator: bool,
+}
+
+fn get_names(labels: impl Iterator<Item = String>) -> String {
+    labels
+        .map(|label| label.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+pub fn parse_mode(value: &str) -> Result<GameMode, String> {
+    GameMode::ALL
+        .into_iter()
+        .find(|mode| mode.get_label().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            format!(
+                "expected one of: {}",
+                get_names(GameMode::ALL.iter().map(|mode| mode.get_label()))
+            )
+        })
+}
+
+pub fn parse_difficulty(value: &str) -> Result<Difficulty, String> {
+    Difficulty::ALL
+        .into_iter()
+        .find(|difficulty| difficulty.get_label().eq_ignore_ascii_case(value))
+        .ok_or_else(|| {
+            format!(
+                "expected one of: {}",
+                get_names(
+                    Difficulty::ALL
+                        .iter()
+                        .map(|difficulty| difficulty.get_label())
+                )
+            )
+        })
+}
+
+pub fn parse_window_size(value: &str) -> Result<(f32, f32), String> {
+    let invalid = || format!("expected WIDTHxHEIGHT like 1280x720, got {}", value);
+    let (width, height) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let width: u32 = width.trim().parse().map_err(|_| invalid())?;
+    let height: u32 = height.trim().parse().map_err(|_| invalid())?;
+    if (width as f32) < MIN_WINDOW_WIDTH || (height as f32) < MIN_WINDOW_HEIGHT {
+        return Err(format!(
+            "the window has to be at least {}x{}",
+            MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT
+        ));
+    }
+    Ok((width as f32, height as f32))
+}
+
+// words are typed with the letter keys, so anything else in the list is left out
+pub fn parse_word_list(contents: &str) -> Result<Vec<String>, String> {
+    let words: Vec<String> = contents
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|word| !word.is_empty() && word.chars().all(|letter| letter.is_ascii_lowercase()))
+        .collect();
+    if words.is_empty() {
+        return Err(String::from(
+            "the word list has no words made of the letters a-z",
+        ));
+    }
+    Ok(words)
+}
+
+pub fn read_word_list(path: &Path) -> Result<Vec<String>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    parse_word_list(&contents).map_err(|err| format!("{}: {}", path.display(), err))
+}
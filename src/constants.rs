@@ -1,3 +1,4 @@
+#[allow(clippy::redundant_static_lifetimes)]
 pub const SOURCE_WORDS: [&'static str; 160] = [
     "about",
     "above",
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::OnceLock;
+
+use ggez::graphics::Color;
+use ggez::{Context, GameError, GameResult};
+use rand::{seq::SliceRandom, Rng};
+use serde::Deserialize;
+
+use crate::preset::Difficulty;
+use crate::storage;
+use crate::word::WordEffect;
+
+pub const EFFECTS_FILE: &str = "/effects.toml";
+const DEFAULT_EFFECTS: &str = include_str!("../resources/effects.toml");
+
+// fallbacks for definitions that leave out their value
+pub const SLOW_DOWN_GAME_SPEED: u32 = 25;
+pub const SHORT_WORD_LENGTH_LIMIT: u32 = 3;
+pub const DOUBLE_SCORE_MULTIPLIER: u32 = 2;
+pub const BOMB_SCORE_PERCENT: u32 = 50;
+pub const SPEED_BURST_PERCENT: u32 = 150;
+
+pub const HAZARD_SCORE_MULTIPLIER: u32 = 2;
+
+// what happens when an effect is picked up while it is still active
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StackingRule {
+    // the timer starts over
+    Refresh,
+    // the full duration is added to the time left
+    Extend,
+    // the timer starts over and the effect gets one more stack
+    Stack,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EffectConfig {
+    // instant effects have no duration and never become active, charges such as the shield
+    // last until they are consumed
+    pub duration: f32,
+    pub stacking: StackingRule,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct EffectDefinition {
+    pub effect: WordEffect,
+    pub weight: u32,
+    #[serde(default)]
+    pub min_score: u32,
+    #[serde(default, deserialize_with = "storage::deserialize_enum_map")]
+    pub difficulty_weights: HashMap<Difficulty, u32>,
+    pub color: [f32; 4],
+    pub font: String,
+    pub duration: f32,
+    pub stacking: StackingRule,
+    // the meaning depends on the effect, e.g. the falling speed of a slow down
+    #[serde(default)]
+    pub value: Option<u32>,
+}
+
+impl EffectDefinition {
+    pub fn get_weight(&self, difficulty: Difficulty) -> u32 {
+        self.difficulty_weights
+            .get(&difficulty)
+            .copied()
+            .unwrap_or(self.weight)
+    }
+
+    pub fn get_config(&self) -> EffectConfig {
+        EffectConfig {
+            duration: self.duration,
+            stacking: self.stacking,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+pub struct EffectTable {
+    #[serde(rename = "effect")]
+    pub definitions: Vec<EffectDefinition>,
+}
+
+impl Default for EffectTable {
+    fn default() -> Self {
+        get_default_effect_table().clone()
+    }
+}
+
+impl EffectTable {
+    pub fn from_toml_str(contents: &str) -> GameResult<EffectTable> {
+        let table: EffectTable = toml::from_str(contents).map_err(|err| {
+            GameError::ResourceLoadError(format!("invalid effects file: {}", err))
+        })?;
+        if let Some(missing) = WordEffect::ALL
+            .iter()
+            .find(|&&effect| table.get(effect).is_none())
+        {
+            return Err(GameError::ResourceLoadError(format!(
+                "invalid effects file: no definition for {:?}",
+                missing
+            )));
+        }
+        Ok(table)
+    }
+
+    pub fn get(&self, effect: WordEffect) -> Option<&EffectDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.effect == effect)
+    }
+
+    pub fn get_color(&self, effect: Option<WordEffect>) -> Color {
+        match effect.and_then(|effect| self.get(effect)) {
+            Some(definition) => Color::from(definition.color),
+            None => Color::WHITE,
+        }
+    }
+
+    pub fn get_font(&self, effect: Option<WordEffect>, default_font: &str) -> String {
+        match effect.and_then(|effect| self.get(effect)) {
+            Some(definition) => definition.font.clone(),
+            None => String::from(default_font),
+        }
+    }
+
+    pub fn get_value(&self, effect: WordEffect, default_value: u32) -> u32 {
+        self.get(effect)
+            .and_then(|definition| definition.value)
+            .unwrap_or(default_value)
+    }
+
+    // rolls the chance first and then picks one of the effects by weight, leaving out the ones
+    // the score is still too low for
+    pub fn roll_effect<R: Rng + ?Sized>(
+        &self,
+        chance: u32,
+        effects: &[WordEffect],
+        score: u32,
+        difficulty: Difficulty,
+        rng: &mut R,
+    ) -> Option<WordEffect> {
+        if rng.gen_range(1..=100) > chance {
+            return None;
+        }
+        let candidates: Vec<&EffectDefinition> = self
+            .definitions
+            .iter()
+            .filter(|definition| effects.contains(&definition.effect))
+            .filter(|definition| score >= definition.min_score)
+            .collect();
+        candidates
+            .choose_weighted(rng, |definition| definition.get_weight(difficulty))
+            .ok()
+            .map(|definition| definition.effect)
+    }
+}
+
+pub fn get_default_effect_table() -> &'static EffectTable {
+    static DEFAULT_EFFECT_TABLE: OnceLock<EffectTable> = OnceLock::new();
+    DEFAULT_EFFECT_TABLE.get_or_init(|| EffectTable::from_toml_str(DEFAULT_EFFECTS).unwrap())
+}
+
+pub fn load_effect_table(ctx: &Context) -> GameResult<EffectTable> {
+    let mut contents = String::new();
+    ctx.fs.open(EFFECTS_FILE)?.read_to_string(&mut contents)?;
+    EffectTable::from_toml_str(&contents)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ActiveEffect {
+    pub effect: WordEffect,
+    pub time_left: f32,
+    pub duration: f32,
+    pub stacks: u32,
+}
+
+impl ActiveEffect {
+    // how much of the effect is left, between 0 and 1, charges never drain
+    pub fn get_remaining_fraction(&self) -> f32 {
+        if self.duration.is_infinite() {
+            1.0
+        } else {
+            (self.time_left / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActiveEffects {
+    active: Vec<ActiveEffect>,
+    configs: HashMap<WordEffect, EffectConfig>,
+    // added on top of every configured power-up duration, bought with upgrades
+    bonus_duration: f32,
+}
+
+impl Default for ActiveEffects {
+    fn default() -> Self {
+        Self::from_table(get_default_effect_table())
+    }
+}
+
+impl ActiveEffects {
+    pub fn from_table(table: &EffectTable) -> Self {
+        Self {
+            active: Vec::new(),
+            configs: table
+                .definitions
+                .iter()
+                .map(|definition| (definition.effect, definition.get_config()))
+                .collect(),
+            bonus_duration: 0.0,
+        }
+    }
+
+    pub fn get_config(&self, effect: WordEffect) -> EffectConfig {
+        self.configs.get(&effect).copied().unwrap_or(EffectConfig {
+            duration: 0.0,
+            stacking: StackingRule::Refresh,
+        })
+    }
+
+    pub fn set_config(&mut self, effect: WordEffect, config: EffectConfig) {
+        self.configs.insert(effect, config);
+    }
+
+    pub fn set_duration(&mut self, effect: WordEffect, duration: f32) {
+        let config = EffectConfig {
+            duration,
+            ..self.get_config(effect)
+        };
+        self.set_config(effect, config);
+    }
+
+    pub fn get_duration(&self, effect: WordEffect) -> f32 {
+        let duration = self.get_config(effect).duration;
+        if duration > 0.0 && !effect.is_hazard() {
+            duration + self.bonus_duration
+        } else {
+            duration
+        }
+    }
+
+    pub fn add_bonus_duration(&mut self, seconds: f32) {
+        self.bonus_duration += seconds;
+    }
+
+    pub fn apply(&mut self, effect: WordEffect) {
+        let duration = self.get_duration(effect);
+        if duration <= 0.0 {
+            return;
+        }
+        let stacking = self.get_config(effect).stacking;
+        match self
+            .active
+            .iter_mut()
+            .find(|active| active.effect == effect)
+        {
+            Some(active) => match stacking {
+                StackingRule::Refresh => {
+                    active.time_left = duration;
+                    active.duration = duration;
+                }
+                StackingRule::Extend => {
+                    active.time_left += duration;
+                    active.duration = active.time_left.max(active.duration);
+                }
+                StackingRule::Stack => {
+                    active.time_left = duration;
+                    active.duration = duration;
+                    active.stacks += 1;
+                }
+            },
+            None => self.active.push(ActiveEffect {
+                effect,
+                time_left: duration,
+                duration,
+                stacks: 1,
+            }),
+        }
+    }
+
+    // returns the effects that ran out during this tick
+    pub fn tick(&mut self, last_frame_length: f32) -> Vec<WordEffect> {
+        let mut expired = Vec::new();
+        self.active.retain_mut(|active| {
+            active.time_left -= last_frame_length;
+            if active.time_left <= 0.0 {
+                expired.push(active.effect);
+                false
+            } else {
+                true
+            }
+        });
+        expired
+    }
+
+    // uses up one stack of the effect, returns false if it was not active
+    pub fn consume(&mut self, effect: WordEffect) -> bool {
+        let Some(index) = self
+            .active
+            .iter()
+            .position(|active| active.effect == effect)
+        else {
+            return false;
+        };
+        if self.active[index].stacks > 1 {
+            self.active[index].stacks -= 1;
+        } else {
+            self.active.remove(index);
+        }
+        true
+    }
+
+    pub fn get(&self, effect: WordEffect) -> Option<&ActiveEffect> {
+        self.active.iter().find(|active| active.effect == effect)
+    }
+
+    pub fn is_active(&self, effect: WordEffect) -> bool {
+        self.get(effect).is_some()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActiveEffect> {
+        self.active.iter()
+    }
+
+    // ends every active effect, the configured durations stay
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn reset(&mut self) {
+        self.active.clear();
+        self.bonus_duration = 0.0;
+    }
+}
@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ggez::graphics::FontData;
+use ggez::{Context, GameResult};
+
+pub const DEFAULT_WORDS_FONT: &str = "GravitasOne";
+pub const BUNDLED_FONTS: [&str; 3] = ["GravitasOne", "Creepster", "BungeeShade"];
+// menus, labels and power-ups
+pub const UI_FONT: &str = "BungeeShade";
+// lost games and hazards
+pub const DANGER_FONT: &str = "Creepster";
+// ggez registers this font itself, so text can always be drawn with it
+pub const FALLBACK_FONT: &str = "LiberationMono-Regular";
+const FONT_EXTENSIONS: [&str; 2] = ["ttf", "otf"];
+
+pub fn is_font_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            FONT_EXTENSIONS
+                .iter()
+                .any(|font_ext| ext.eq_ignore_ascii_case(font_ext))
+        })
+}
+
+// fonts are registered under their file name without the extension
+pub fn get_font_name(path: &Path) -> Option<String> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(String::from)
+}
+
+// the font when it was loaded, the fallback font otherwise
+pub fn resolve_font<'a>(fonts: &'a [String], name: &'a str) -> &'a str {
+    if fonts.iter().any(|font| font == name) {
+        name
+    } else {
+        FALLBACK_FONT
+    }
+}
+
+pub fn get_user_fonts_dir(data_dir: &Path) -> PathBuf {
+    data_dir.join("fonts")
+}
+
+// registers the fonts of the resources and the user fonts directory and returns their names,
+// a user font with the same name as a bundled one replaces it
+pub fn load_fonts(ctx: &mut Context, user_fonts_dir: Option<&Path>) -> Vec<String> {
+    let mut fonts = Vec::new();
+
+    let mut resource_paths: Vec<PathBuf> = match ctx.fs.read_dir("/") {
+        Ok(paths) => paths.filter(|path| is_font_file(path)).collect(),
+        Err(err) => {
+            eprintln!("could not list the bundled fonts: {}", err);
+            Vec::new()
+        }
+    };
+    resource_paths.sort();
+    for path in resource_paths {
+        let font_data = FontData::from_path(ctx, &path);
+        register_font(ctx, &mut fonts, &path, font_data);
+    }
+
+    if let Some(user_fonts_dir) = user_fonts_dir {
+        let mut user_paths: Vec<PathBuf> = fs::read_dir(user_fonts_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| is_font_file(path))
+                    .collect()
+            })
+            .unwrap_or_default();
+        user_paths.sort();
+        for path in user_paths {
+            let font_data = fs::read(&path)
+                .map_err(Into::into)
+                .and_then(FontData::from_vec);
+            register_font(ctx, &mut fonts, &path, font_data);
+        }
+    }
+
+    fonts
+}
+
+fn register_font(
+    ctx: &mut Context,
+    fonts: &mut Vec<String>,
+    path: &Path,
+    font_data: GameResult<FontData>,
+) {
+    let Some(name) = get_font_name(path) else {
+        eprintln!("skipping the font {}: invalid file name", path.display());
+        return;
+    };
+    match font_data {
+        Ok(font_data) => {
+            ctx.gfx.add_font(&name, font_data);
+            if !fonts.contains(&name) {
+                fonts.push(name);
+            }
+        }
+        Err(err) => eprintln!("skipping the font {}: {}", path.display(), err),
+    }
+}
@@ -1,4 +1,5 @@
 use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
 
 use ggez::conf::Conf;
 use ggez::event::EventHandler;
@@ -7,18 +8,79 @@ use ggez::input::keyboard;
 use ggez::mint::Point2;
 use ggez::winit::event::VirtualKeyCode;
 use ggez::{Context, GameResult};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::SeedableRng;
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::achievements::{
+    get_current_day, Achievement, Achievements, RunStats, ACHIEVEMENT_TOAST_DURATION,
+};
+use crate::bindings::{Action, Bindings};
+use crate::campaign::Campaign;
 use crate::constants::SOURCE_WORDS;
-use crate::menu::{Menu};
-use crate::word::{Word, WordEffect};
+use crate::effects::{
+    ActiveEffects, EffectTable, BOMB_SCORE_PERCENT, DOUBLE_SCORE_MULTIPLIER,
+    HAZARD_SCORE_MULTIPLIER, SHORT_WORD_LENGTH_LIMIT, SLOW_DOWN_GAME_SPEED, SPEED_BURST_PERCENT,
+};
+use crate::fonts::{self, BUNDLED_FONTS, DANGER_FONT, DEFAULT_WORDS_FONT, UI_FONT};
+use crate::layout::{
+    get_next_option, DisplayMode, Layout, DEFAULT_UI_SCALE, DEFAULT_WORD_SIZE, UI_SCALES,
+    WORD_SIZES,
+};
+use crate::lives::{DamageFeedback, LIFE_BREAK_LENGTH};
+use crate::menu::{MenuItem, MenuMove, MenuPage, MenuTarget, MenuTree};
+use crate::motion::{Motion, MotionPattern};
+use crate::preset::{Difficulty, Preset, PresetField, PresetSettings};
+use crate::progression::{
+    self, get_font_unlock_level, get_theme_unlock_level, get_unlock_level, Profile, Unlock,
+};
+use crate::results::{GameSummary, ResultsHistory};
+use crate::rules::{GameRules, INITIAL_GAME_SPEED, INITIAL_TIME_UNTIL_NEXT_WORD};
+use crate::scoring::WordScore;
+use crate::settings::Settings;
+use crate::spectator::{
+    GameSnapshot, SpectatorEvent, SpectatorServer, WordSnapshot, DEFAULT_SPECTATOR_PORT,
+    SNAPSHOT_INTERVAL,
+};
+use crate::streak::{Streak, STREAK_BREAK_ANIMATION_LENGTH};
+use crate::theme::{get_bundled_themes, with_alpha, Theme, DEFAULT_THEME_NAME};
+use crate::wave::{Upgrade, WaveState, WAVE_SPEED_INCREASE};
+use crate::word::{HazardTrigger, Word, WordEffect, WordVisibility, DEFAULT_EFFECT_CHANCE};
 
-const WORD_SCORE: u32 = 10;
+const SLOWER_BASE_SPEED_UPGRADE: u32 = 10;
+const MIN_BASE_GAME_SPEED: u32 = 20;
+const LONGER_POWER_UPS_UPGRADE: f32 = 2.0;
+const HIGHER_EFFECT_CHANCE_UPGRADE: u32 = 5;
+const MAX_EFFECT_CHANCE: u32 = 60;
 
-const INITIAL_TIME_UNTIL_NEXT_WORD: f32 = 1.0;
-const INITIAL_GAME_SPEED: u32 = 50;
+const SPLIT_WORD_OFFSET: f32 = 60.0;
+
+const EFFECT_BANNER_DURATION: f32 = 1.5;
+// layout sizes are given for the reference window, see the layout module
+const HUD_TEXT_SIZE: f32 = 50.0;
+const HUD_MARGIN: f32 = 30.0;
+// horizontal room a word needs at the default word size
+const WORD_ROOM: f32 = 200.0;
+const EFFECTS_HUD_WIDTH: f32 = 320.0;
+const EFFECTS_HUD_ROW_HEIGHT: f32 = 50.0;
+const EFFECT_ICON_SIZE: f32 = 30.0;
+const EFFECT_BAR_HEIGHT: f32 = 8.0;
+
+const XP_BAR_WIDTH: f32 = 400.0;
+const XP_BAR_HEIGHT: f32 = 14.0;
+
+const LIFE_ICON_SIZE: f32 = 36.0;
+const LIFE_ICON_SPACING: f32 = 46.0;
+const EXPLOSION_LETTER_WIDTH: f32 = 30.0;
+const EXPLOSION_SPREAD: f32 = 120.0;
+const DAMAGE_FLASH_OPACITY: f32 = 0.35;
+
+const BLIND_REVEAL_TIME: f32 = 1.5;
+const BLIND_PERFECT_WORD_MULTIPLIER: u32 = 2;
+
+// word scores shown at once on the results screen
+const RESULTS_DETAILS_LINES: usize = 8;
 
 pub fn create_key_codes_map() -> HashMap<keyboard::KeyCode, char> {
     HashMap::from([
@@ -51,718 +113,4286 @@ pub fn create_key_codes_map() -> HashMap<keyboard::KeyCode, char> {
     ])
 }
 
-fn create_main_menu() -> Menu {
-    Menu {
-        options: Vec::from(["PLAY".to_string(), "SETTINGS".to_string()]),
-        selected_option_index: 0,
-    }
+// ids of the menu pages, a page is reached through the submenu items pointing at it
+const MAIN_PAGE: &str = "main";
+const SETTINGS_PAGE: &str = "settings";
+const FONTS_PAGE: &str = "fonts";
+const THEMES_PAGE: &str = "themes";
+const MODES_PAGE: &str = "modes";
+const LEVELS_PAGE: &str = "levels";
+const DIFFICULTY_PAGE: &str = "difficulty";
+const CUSTOM_PRESET_PAGE: &str = "custom_preset";
+const RESULTS_PAGE: &str = "results";
+const ACHIEVEMENTS_PAGE: &str = "achievements";
+const BINDINGS_PAGE: &str = "bindings";
+const PAUSE_PAGE: &str = "pause";
+const SHOP_PAGE: &str = "shop";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MenuCommand {
+    Play,
+    Resume,
+    Restart,
+    QuitToMenu,
+    ToggleSpectator,
+    ToggleHideTypedLetters,
+    CycleUiScale,
+    CycleWordSize,
+    CycleDisplayMode,
+    SelectFont(usize),
+    SelectTheme(usize),
+    SelectMode(GameMode),
+    SelectDifficulty(Difficulty),
+    CyclePresetField(PresetField),
+    StartLevel(usize),
+    Rebind(Action),
+    ResetBindings,
+    BuyUpgrade(Upgrade),
+    StartNextWave,
+    // run when their page is left
+    SavePresetSettings,
+    DropThemePreview,
+    ClearBindingsMessage,
+    HideWordScores,
+}
+
+fn create_menu_tree() -> MenuTree<MenuCommand> {
+    let profile = Profile::default();
+    let mut menus = MenuTree::new(MAIN_PAGE, MenuPage::new(get_main_menu_items()));
+    menus.add_page(SETTINGS_PAGE, MenuPage::new(get_settings_menu_items()));
+    menus.add_page(
+        FONTS_PAGE,
+        MenuPage::new(get_fonts_menu_items(
+            &BUNDLED_FONTS.map(String::from),
+            &profile,
+        )),
+    );
+    menus.add_page(
+        THEMES_PAGE,
+        MenuPage::new(get_themes_menu_items(get_bundled_themes(), &profile))
+            .with_back_command(MenuCommand::DropThemePreview),
+    );
+    menus.add_page(MODES_PAGE, MenuPage::new(get_modes_menu_items(&profile)));
+    menus.add_page(LEVELS_PAGE, MenuPage::new(get_levels_menu_items(None)));
+    menus.add_page(
+        DIFFICULTY_PAGE,
+        MenuPage::new(get_difficulty_menu_items(&profile)),
+    );
+    menus.add_page(
+        CUSTOM_PRESET_PAGE,
+        MenuPage::new(get_custom_preset_menu_items(
+            &PresetSettings::default().custom_preset,
+        ))
+        .with_back_command(MenuCommand::SavePresetSettings),
+    );
+    menus.add_page(
+        RESULTS_PAGE,
+        MenuPage::new(Vec::new()).with_back_command(MenuCommand::HideWordScores),
+    );
+    menus.add_page(ACHIEVEMENTS_PAGE, MenuPage::new(Vec::new()));
+    menus.add_page(
+        BINDINGS_PAGE,
+        MenuPage::new(get_bindings_menu_items(&Bindings::default()))
+            .with_back_command(MenuCommand::ClearBindingsMessage),
+    );
+    menus.add_page(
+        PAUSE_PAGE,
+        MenuPage::new(get_pause_menu_items()).with_back_command(MenuCommand::Resume),
+    );
+    menus.add_page(SHOP_PAGE, MenuPage::new(get_shop_menu_items()));
+    menus
+}
+
+fn get_main_menu_items() -> Vec<MenuItem<MenuCommand>> {
+    Vec::from([
+        MenuItem::command("PLAY".to_string(), MenuCommand::Play),
+        MenuItem::submenu("SETTINGS".to_string(), SETTINGS_PAGE),
+        MenuItem::submenu(get_mode_option_label(GameMode::Endless), MODES_PAGE),
+        MenuItem::submenu(
+            get_difficulty_option_label(Difficulty::Normal),
+            DIFFICULTY_PAGE,
+        ),
+        MenuItem::submenu("ACHIEVEMENTS".to_string(), ACHIEVEMENTS_PAGE),
+    ])
 }
 
-fn create_settings_menu() -> Menu {
-    Menu {
-        options: Vec::from(["CHANGE FONT".to_string(), "CHANGE BG COLOR".to_string()]),
-        selected_option_index: 0,
+fn get_mode_option_label(mode: GameMode) -> String {
+    format!("MODE: {}", mode.get_label())
+}
+
+fn get_levels_menu_items(campaign: Option<&Campaign>) -> Vec<MenuItem<MenuCommand>> {
+    match campaign {
+        Some(campaign) if !campaign.levels.is_empty() => (0..campaign.levels.len())
+            .map(|idx| {
+                MenuItem::command(campaign.get_level_label(idx), MenuCommand::StartLevel(idx))
+            })
+            .collect(),
+        _ => Vec::from([MenuItem::back("NO LEVELS FOUND".to_string())]),
     }
 }
 
-fn create_fonts_menu() -> Menu {
-    Menu {
-        options: Vec::from(["GravitasOne".to_string(), "Creepster".to_string()]),
-        selected_option_index: 0,
+fn get_default_source_words() -> Vec<String> {
+    SOURCE_WORDS.iter().map(|word| word.to_string()).collect()
+}
+
+fn get_pause_menu_items() -> Vec<MenuItem<MenuCommand>> {
+    Vec::from([
+        MenuItem::command("RESUME".to_string(), MenuCommand::Resume),
+        MenuItem::command("RESTART".to_string(), MenuCommand::Restart),
+        MenuItem::submenu("SETTINGS".to_string(), SETTINGS_PAGE),
+        MenuItem::command("QUIT TO MENU".to_string(), MenuCommand::QuitToMenu),
+    ])
+}
+
+fn get_shop_menu_items() -> Vec<MenuItem<MenuCommand>> {
+    let mut items: Vec<MenuItem<MenuCommand>> = Upgrade::ALL
+        .iter()
+        .map(|upgrade| MenuItem::command(upgrade.get_label(), MenuCommand::BuyUpgrade(*upgrade)))
+        .collect();
+    items.push(MenuItem::command(
+        "NEXT WAVE".to_string(),
+        MenuCommand::StartNextWave,
+    ));
+    items
+}
+
+fn get_difficulty_option_label(difficulty: Difficulty) -> String {
+    format!("DIFFICULTY: {}", difficulty.get_label())
+}
+
+// locked options show the level they unlock at
+fn get_unlockable_option_label(label: String, unlock: Unlock, profile: &Profile) -> String {
+    if profile.is_unlocked(unlock) {
+        label
+    } else {
+        format!("{} - LV {}", label, get_unlock_level(unlock))
     }
 }
 
-fn create_bg_colors_menu() -> Menu {
-    Menu {
-        options: Vec::from(["PURPLE".to_string(), "GREEN".to_string()]),
-        selected_option_index: 0,
+fn get_difficulty_menu_items(profile: &Profile) -> Vec<MenuItem<MenuCommand>> {
+    let mut items: Vec<MenuItem<MenuCommand>> = Difficulty::ALL
+        .iter()
+        .map(|difficulty| {
+            MenuItem::command(
+                get_unlockable_option_label(
+                    difficulty.get_label(),
+                    Unlock::Difficulty(*difficulty),
+                    profile,
+                ),
+                MenuCommand::SelectDifficulty(*difficulty),
+            )
+        })
+        .collect();
+    items.push(MenuItem::submenu(
+        "EDIT CUSTOM".to_string(),
+        CUSTOM_PRESET_PAGE,
+    ));
+    items
+}
+
+fn get_custom_preset_menu_items(preset: &Preset) -> Vec<MenuItem<MenuCommand>> {
+    PresetField::ALL
+        .iter()
+        .map(|field| {
+            MenuItem::command(
+                field.get_label(preset),
+                MenuCommand::CyclePresetField(*field),
+            )
+        })
+        .collect()
+}
+
+fn get_modes_menu_items(profile: &Profile) -> Vec<MenuItem<MenuCommand>> {
+    GameMode::ALL
+        .iter()
+        .map(|mode| {
+            MenuItem::command(
+                get_unlockable_option_label(mode.get_label(), Unlock::Mode(*mode), profile),
+                MenuCommand::SelectMode(*mode),
+            )
+        })
+        .collect()
+}
+
+fn get_settings_menu_items() -> Vec<MenuItem<MenuCommand>> {
+    Vec::from([
+        MenuItem::submenu("CHANGE FONT".to_string(), FONTS_PAGE),
+        MenuItem::submenu("CHANGE THEME".to_string(), THEMES_PAGE),
+        MenuItem::command(
+            get_spectator_option_label(false),
+            MenuCommand::ToggleSpectator,
+        ),
+        MenuItem::command(
+            get_hide_typed_letters_option_label(false),
+            MenuCommand::ToggleHideTypedLetters,
+        ),
+        MenuItem::command(
+            get_ui_scale_option_label(DEFAULT_UI_SCALE),
+            MenuCommand::CycleUiScale,
+        ),
+        MenuItem::command(
+            get_word_size_option_label(DEFAULT_WORD_SIZE),
+            MenuCommand::CycleWordSize,
+        ),
+        MenuItem::command(
+            get_display_mode_option_label(DisplayMode::default()),
+            MenuCommand::CycleDisplayMode,
+        ),
+        MenuItem::submenu("KEY BINDINGS".to_string(), BINDINGS_PAGE),
+    ])
+}
+
+fn get_hide_typed_letters_option_label(is_enabled: bool) -> String {
+    if is_enabled {
+        String::from("HIDE TYPED: ON")
+    } else {
+        String::from("HIDE TYPED: OFF")
     }
 }
 
-fn get_color_by_label(label: String) -> Color {
-    if label == String::from("PURPLE") {
-        Color::new(0.19, 0.2, 0.45, 0.65)
-    } else if label == String::from("GREEN") {
-        Color::new(0.2, 0.4, 0.25, 0.85)
+fn get_ui_scale_option_label(ui_scale: u32) -> String {
+    format!("UI SCALE: {}%", ui_scale)
+}
+
+fn get_word_size_option_label(word_size: u32) -> String {
+    format!("WORD SIZE: {}", word_size)
+}
+
+fn get_display_mode_option_label(display_mode: DisplayMode) -> String {
+    format!("DISPLAY: {}", display_mode.get_label())
+}
+
+fn get_spectator_option_label(is_enabled: bool) -> String {
+    if is_enabled {
+        String::from("SPECTATOR: ON")
     } else {
-        Color::WHITE
+        String::from("SPECTATOR: OFF")
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum MenuType {
-    Main,
-    Settings,
-    Fonts,
-    BgColors,
-    None,
+fn get_fonts_menu_items(fonts: &[String], profile: &Profile) -> Vec<MenuItem<MenuCommand>> {
+    fonts
+        .iter()
+        .enumerate()
+        .map(|(idx, font)| {
+            let label = if profile.is_font_unlocked(font) {
+                font.clone()
+            } else {
+                format!("{} - LV {}", font, get_font_unlock_level(font))
+            };
+            MenuItem::command(label, MenuCommand::SelectFont(idx))
+        })
+        .collect()
+}
+
+fn get_bindings_menu_items(bindings: &Bindings) -> Vec<MenuItem<MenuCommand>> {
+    let mut items: Vec<MenuItem<MenuCommand>> = Action::ALL
+        .iter()
+        .map(|action| {
+            MenuItem::command(
+                bindings.get_option_label(*action),
+                MenuCommand::Rebind(*action),
+            )
+        })
+        .collect();
+    items.push(MenuItem::command(
+        String::from("RESET TO DEFAULTS"),
+        MenuCommand::ResetBindings,
+    ));
+    items
+}
+
+fn get_themes_menu_items(themes: &[Theme], profile: &Profile) -> Vec<MenuItem<MenuCommand>> {
+    themes
+        .iter()
+        .enumerate()
+        .map(|(idx, theme)| {
+            let label = if profile.is_theme_unlocked(&theme.name) {
+                theme.get_label()
+            } else {
+                format!(
+                    "{} - LV {}",
+                    theme.get_label(),
+                    get_theme_unlock_level(&theme.name)
+                )
+            };
+            MenuItem::command(label, MenuCommand::SelectTheme(idx))
+        })
+        .collect()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GameMode {
+    Endless,
+    Waves,
+    Campaign,
+    Blind,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 4] = [
+        GameMode::Endless,
+        GameMode::Waves,
+        GameMode::Campaign,
+        GameMode::Blind,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            GameMode::Endless => String::from("ENDLESS"),
+            GameMode::Waves => String::from("WAVES"),
+            GameMode::Campaign => String::from("CAMPAIGN"),
+            GameMode::Blind => String::from("BLIND"),
+        }
+    }
+
+    // campaign levels pick their own mix
+    pub fn get_motion_patterns(&self) -> Vec<MotionPattern> {
+        match self {
+            GameMode::Waves => Vec::from(MotionPattern::ALL),
+            GameMode::Endless | GameMode::Campaign | GameMode::Blind => {
+                vec![MotionPattern::Linear]
+            }
+        }
+    }
 }
 
 pub struct Game {
     screen_height: f32,
     screen_width: f32,
-    bg_color: Color,
+    ui_scale: u32,
+    word_size: u32,
+    display_mode: DisplayMode,
+    // the saved values, the command line only changes the current ones for the session
+    saved_display_mode: DisplayMode,
+    saved_difficulty: Difficulty,
+    // applied on the next update since switching needs the context
+    is_display_mode_changed: bool,
+    themes: Vec<Theme>,
+    theme: Theme,
     key_codes_map: HashMap<keyboard::KeyCode, char>,
     is_game_running: bool,
+    is_paused: bool,
     words: VecDeque<Word>,
+    fonts: Vec<String>,
     words_font: String,
     next_word_loop_length: f32,
     current_score: u32,
     life_points: u32,
+    damage_feedback: DamageFeedback,
     game_speed: u32,
-    current_menu_type: MenuType,
-    main_menu: Menu,
-    settings_menu: Menu,
-    fonts_menu: Menu,
-    bg_colors_menu: Menu,
+    menus: MenuTree<MenuCommand>,
+    bindings: Bindings,
+    // the action waiting for its new key
+    rebinding_action: Option<Action>,
+    bindings_message: Option<String>,
+    preset_settings: PresetSettings,
+    preset_settings_path: Option<PathBuf>,
+    settings_path: Option<PathBuf>,
+    // whether the spectator server is turned on in the settings, the command line can also start it
+    spectator_setting: bool,
+    results: ResultsHistory,
+    achievements: Achievements,
+    profile: Profile,
+    // levels the player went up by at the end of the last game
+    levels_gained: u32,
+    run_stats: RunStats,
+    // newly unlocked achievements, the first one is shown until its time runs out
+    achievement_toasts: VecDeque<(Achievement, f32)>,
+    last_summary: Option<GameSummary>,
+    previous_best_score: Option<u32>,
+    game_mode: GameMode,
+    rules: GameRules,
+    source_words: Vec<String>,
+    // a word list given on the command line replaces the built-in words
+    custom_source_words: Option<Vec<String>>,
+    rng: StdRng,
+    // reseeds the rng at the start of every game so each one plays out the same
+    seed: Option<u64>,
+    allowed_effects: Vec<WordEffect>,
+    motion_patterns: Vec<MotionPattern>,
+    completed_words: u32,
+    streak: Streak,
+    hide_typed_letters: bool,
+    misremembered_words: Vec<String>,
+    word_scores: Vec<WordScore>,
+    // first shown line of the word scores on the results screen, None shows the summary
+    results_details_offset: Option<usize>,
+    campaign: Option<Campaign>,
+    current_level_index: Option<usize>,
+    end_game_message: String,
+    wave: Option<WaveState>,
+    base_game_speed: u32,
+    effect_table: EffectTable,
+    active_effects: ActiveEffects,
+    // the last picked up effect and how long its banner stays on screen
+    effect_banner: Option<(WordEffect, f32)>,
+    effect_spawn_chance: u32,
     time_until_next_word: Option<f32>,
-    game_speed_before_slow_down: Option<u32>,
     passed_time_since_game_end: Option<f32>,
-    slow_down_time_left: Option<f32>,
-    spawn_only_short_words_time_left: Option<f32>,
+    spectator: Option<SpectatorServer>,
+    time_until_next_snapshot: f32,
 }
 
 impl Game {
     pub fn new(conf: &Conf) -> Game {
-        Game {
+        let mut game = Game {
             is_game_running: false,
+            is_paused: false,
             key_codes_map: create_key_codes_map(),
             next_word_loop_length: INITIAL_TIME_UNTIL_NEXT_WORD,
             words: VecDeque::new(),
-            words_font: String::from("GravitasOne"),
+            fonts: BUNDLED_FONTS.map(String::from).to_vec(),
+            words_font: String::from(DEFAULT_WORDS_FONT),
             screen_height: conf.window_mode.height,
             screen_width: conf.window_mode.width,
-            bg_color: Color::new(0.19, 0.2, 0.45, 0.65),
+            ui_scale: DEFAULT_UI_SCALE,
+            word_size: DEFAULT_WORD_SIZE,
+            display_mode: DisplayMode::default(),
+            saved_display_mode: DisplayMode::default(),
+            saved_difficulty: Difficulty::Normal,
+            is_display_mode_changed: false,
+            themes: get_bundled_themes().clone(),
+            theme: Theme::default(),
             current_score: 0,
             life_points: 0,
+            damage_feedback: DamageFeedback::default(),
             game_speed: INITIAL_GAME_SPEED,
-            current_menu_type: MenuType::Main,
-            main_menu: create_main_menu(),
-            settings_menu: create_settings_menu(),
-            fonts_menu: create_fonts_menu(),
-            bg_colors_menu: create_bg_colors_menu(),
+            menus: create_menu_tree(),
+            bindings: Bindings::default(),
+            rebinding_action: None,
+            bindings_message: None,
+            preset_settings: PresetSettings::default(),
+            preset_settings_path: None,
+            settings_path: None,
+            spectator_setting: false,
+            results: ResultsHistory::default(),
+            achievements: Achievements::default(),
+            profile: Profile::default(),
+            levels_gained: 0,
+            run_stats: RunStats::default(),
+            achievement_toasts: VecDeque::new(),
+            last_summary: None,
+            previous_best_score: None,
+            game_mode: GameMode::Endless,
+            rules: GameRules::default(),
+            source_words: get_default_source_words(),
+            custom_source_words: None,
+            rng: StdRng::from_entropy(),
+            seed: None,
+            allowed_effects: Vec::from(WordEffect::ALL),
+            motion_patterns: vec![MotionPattern::Linear],
+            completed_words: 0,
+            streak: Streak::default(),
+            hide_typed_letters: false,
+            misremembered_words: Vec::new(),
+            word_scores: Vec::new(),
+            results_details_offset: None,
+            campaign: None,
+            current_level_index: None,
+            end_game_message: String::from("YOU LOST"),
+            wave: None,
+            base_game_speed: INITIAL_GAME_SPEED,
+            effect_table: EffectTable::default(),
+            active_effects: ActiveEffects::default(),
+            effect_banner: None,
+            effect_spawn_chance: DEFAULT_EFFECT_CHANCE,
             time_until_next_word: None,
-            game_speed_before_slow_down: None,
             passed_time_since_game_end: None,
-            slow_down_time_left: None,
-            spawn_only_short_words_time_left: None,
-        }
+            spectator: None,
+            time_until_next_snapshot: SNAPSHOT_INTERVAL,
+        };
+        game.select_theme(DEFAULT_THEME_NAME);
+        game
     }
 
-    fn draw_home_screen(&self, canvas: &mut Canvas, ctx: &Context) {
-        match self.current_menu_type {
-            MenuType::Main => self.main_menu.draw(canvas, ctx, self.screen_width),
-            MenuType::Settings => self.settings_menu.draw(canvas, ctx, self.screen_width),
-            MenuType::Fonts => self.fonts_menu.draw(canvas, ctx, self.screen_width),
-            MenuType::BgColors => self.bg_colors_menu.draw(canvas, ctx, self.screen_width),
-            MenuType::None => (),
-        }
+    pub fn set_preset_settings(&mut self, preset_settings: PresetSettings, path: Option<PathBuf>) {
+        self.menus.set_label(
+            &MenuTarget::Submenu(DIFFICULTY_PAGE),
+            get_difficulty_option_label(preset_settings.difficulty),
+        );
+        self.menus
+            .get_page_mut(CUSTOM_PRESET_PAGE)
+            .set_items(get_custom_preset_menu_items(&preset_settings.custom_preset));
+        self.saved_difficulty = preset_settings.difficulty;
+        self.preset_settings = preset_settings;
+        self.preset_settings_path = path;
     }
 
-    fn draw_player_stats(&self, canvas: &mut Canvas) {
-        let mut text = graphics::Text::new(format!("SCORE: {}", self.current_score));
-        text.set_font("BungeeShade");
-        text.set_scale(graphics::PxScale::from(50.0));
-        canvas.draw(
-            &text,
-            graphics::DrawParam::default()
-                .color(Color::new(0.8, 0.4, 0.17, 0.88))
-                .dest(Point2 {
-                    x: 30.0,
-                    y: self.screen_height - 100.0,
-                }),
-        );
+    pub fn set_results_history(&mut self, results: ResultsHistory) {
+        self.results = results;
+    }
 
-        let mut text = graphics::Text::new(format!("LIFES: {}", self.life_points));
-        text.set_font("BungeeShade");
-        text.set_scale(graphics::PxScale::from(50.0));
-        canvas.draw(
-            &text,
-            graphics::DrawParam::default()
-                .color(Color::GREEN)
-                .dest(Point2 {
-                    x: 30.0,
-                    y: self.screen_height - 175.0,
-                }),
+    // the spectator setting is left to the caller since starting the server can fail
+    pub fn set_settings(&mut self, settings: &Settings, path: Option<PathBuf>) {
+        self.select_words_font(&settings.words_font);
+        self.select_theme(&settings.theme);
+        self.hide_typed_letters = settings.hide_typed_letters;
+        self.spectator_setting = settings.spectator;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::ToggleHideTypedLetters),
+            get_hide_typed_letters_option_label(self.hide_typed_letters),
+        );
+        self.ui_scale = settings.ui_scale;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::CycleUiScale),
+            get_ui_scale_option_label(self.ui_scale),
+        );
+        self.word_size = settings.word_size;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::CycleWordSize),
+            get_word_size_option_label(self.word_size),
         );
+        self.display_mode = settings.display_mode;
+        self.saved_display_mode = settings.display_mode;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::CycleDisplayMode),
+            get_display_mode_option_label(self.display_mode),
+        );
+        self.bindings = settings.bindings.clone();
+        self.menus
+            .get_page_mut(BINDINGS_PAGE)
+            .set_items(get_bindings_menu_items(&self.bindings));
+        self.settings_path = path;
     }
 
-    fn draw_words(&self, canvas: &mut Canvas) {
-        for word in &self.words {
-            let mut text = graphics::Text::new(word.get_display_value());
-            let font = match word.effect {
-                Some(_effect) => String::from("BungeeShade"),
-                None => self.words_font.clone(),
-            };
-            text.set_font(font);
-            text.set_scale(graphics::PxScale::from(40.0));
-            canvas.draw(
-                &text,
-                graphics::DrawParam::default()
-                    .color(word.get_color())
-                    .dest(word.position),
-            );
+    pub fn get_settings(&self) -> Settings {
+        Settings {
+            words_font: self.words_font.clone(),
+            theme: self.theme.name.clone(),
+            spectator: self.spectator_setting,
+            hide_typed_letters: self.hide_typed_letters,
+            ui_scale: self.ui_scale,
+            word_size: self.word_size,
+            display_mode: self.saved_display_mode,
+            bindings: self.bindings.clone(),
+            ..Settings::default()
         }
     }
 
-    fn draw_end_game_message(&self, canvas: &mut Canvas, ctx: &Context) {
-        let mut text = graphics::Text::new("YOU LOST");
-        text.set_font("Creepster");
-        text.set_scale(graphics::PxScale::from(100.0));
-        let text_width = text.dimensions(ctx).unwrap().w;
-        canvas.draw(
-            &text,
-            graphics::DrawParam::default()
-                .color(Color::RED)
-                .dest(Point2 {
-                    x: self.screen_width / 2.0 - text_width / 2.0,
-                    y: 100.0,
-                }),
-        );
+    fn save_settings(&self) {
+        if let Some(path) = &self.settings_path {
+            if let Err(err) = self.get_settings().save(path) {
+                eprintln!("could not save the settings: {}", err);
+            }
+        }
     }
 
-    fn start_game(&mut self) -> GameResult {
-        self.is_game_running = true;
-        self.current_score = 0;
-        self.life_points = 0;
-        self.time_until_next_word = Some(INITIAL_TIME_UNTIL_NEXT_WORD);
-        self.game_speed = INITIAL_GAME_SPEED;
-        self.passed_time_since_game_end = None;
-
-        Ok(())
+    // for this session only, the window is expected to be opened in it already
+    pub fn set_display_mode(&mut self, display_mode: DisplayMode) {
+        self.display_mode = display_mode;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::CycleDisplayMode),
+            get_display_mode_option_label(display_mode),
+        );
     }
 
-    fn end_game(&mut self) -> GameResult {
-        self.is_game_running = false;
-        self.words.clear();
-        self.passed_time_since_game_end = Some(0.0);
-        Ok(())
+    // replaces the built-in words, they stay in use after the rules are reset
+    pub fn set_source_words(&mut self, words: Vec<String>) {
+        self.source_words = words.clone();
+        self.custom_source_words = Some(words);
     }
 
-    fn update_words_positions(&mut self, delta_y: f32) {
-        for word in &mut self.words {
-            word.update_position(delta_y)
-        }
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
+        self.rng = StdRng::seed_from_u64(seed);
     }
 
-    fn spawn_new_word(&mut self, length_limit: Option<usize>) {
-        let source_words: Vec<&str>;
-        if let Some(limit) = length_limit {
-            source_words = SOURCE_WORDS
-                .iter()
-                .filter(|&word| word.graphemes(true).count() <= limit)
-                .map(|w| *w)
-                .collect();
-        } else {
-            source_words = Vec::from(SOURCE_WORDS)
+    // returns false when the mode is still locked
+    pub fn set_game_mode(&mut self, mode: GameMode) -> bool {
+        if !self.profile.is_unlocked(Unlock::Mode(mode)) {
+            return false;
         }
-        let word = source_words.choose(&mut rand::thread_rng()).unwrap();
-        let word_position = Point2 {
-            x: rand::thread_rng().gen_range(0.0..self.screen_width - 200.0),
-            y: 0.0,
-        };
-        self.words.push_back(Word::new(word, word_position, 0));
+        self.game_mode = mode;
+        self.menus.set_label(
+            &MenuTarget::Submenu(MODES_PAGE),
+            get_mode_option_label(mode),
+        );
+        self.menus
+            .get_page_mut(MODES_PAGE)
+            .menu
+            .selected_option_index = GameMode::ALL
+            .iter()
+            .position(|game_mode| *game_mode == mode)
+            .unwrap_or(0);
+        true
     }
 
-    fn complete_word(&mut self) {
-        let word = self.words.pop_front().unwrap();
-        self.current_score += WORD_SCORE;
-        self.game_speed += 5;
-        if let Some(effect) = word.effect {
-            self.apply_word_effect(effect)
+    // picks the difficulty for this session only, returns false when it is still locked
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) -> bool {
+        if !self.profile.is_unlocked(Unlock::Difficulty(difficulty)) {
+            return false;
         }
+        self.preset_settings.difficulty = difficulty;
+        self.menus.set_label(
+            &MenuTarget::Submenu(DIFFICULTY_PAGE),
+            get_difficulty_option_label(difficulty),
+        );
+        true
     }
 
-    fn apply_word_effect(&mut self, effect: WordEffect) {
-        match effect {
-            WordEffect::AddLife => self.life_points += 1,
-            WordEffect::SlowDown => {
-                self.slow_down_time_left = Some(5.0);
-                self.game_speed_before_slow_down = Some(self.game_speed);
-                self.game_speed = 25;
-            }
-            WordEffect::SpawnOnlyShortWords => self.spawn_only_short_words_time_left = Some(5.0),
+    // starts the selected mode as if play was chosen, the campaign continues at the last
+    // unlocked level
+    pub fn skip_menu(&mut self) -> GameResult {
+        if self.game_mode != GameMode::Campaign {
+            self.reset_rules();
+            return self.start_game();
         }
-    }
-
-    fn update_slow_down_time_left(&mut self, current_time_left: f32, last_frame_length: f32) {
-        if current_time_left <= 0.0 {
-            self.slow_down_time_left = None;
-            if let Some(game_speed_before_slow_down) = self.game_speed_before_slow_down {
-                self.game_speed = game_speed_before_slow_down;
-                self.game_speed_before_slow_down = None
+        let last_unlocked_level = self.campaign.as_ref().and_then(|campaign| {
+            (0..campaign.levels.len())
+                .rev()
+                .find(|index| campaign.is_unlocked(*index))
+        });
+        match last_unlocked_level {
+            Some(level_index) => self.start_level(level_index),
+            None => {
+                self.menus.open(LEVELS_PAGE);
+                Ok(())
             }
-        } else {
-            self.slow_down_time_left = Some(current_time_left - last_frame_length);
         }
     }
 
-    fn handle_input_key_in_main_menu(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
-        match input_key_code {
-            keyboard::KeyCode::Up => self.main_menu.handle_move_up(),
-            keyboard::KeyCode::Down => self.main_menu.handle_move_down(),
-            keyboard::KeyCode::Space => {
-                let selected_option = self.main_menu.get_selected_option();
-                if *selected_option == String::from("PLAY") {
-                    self.start_game()?
-                } else if *selected_option == String::from("SETTINGS") {
-                    self.current_menu_type = MenuType::Settings
-                }
-            }
-            _ => (),
-        };
-
-        Ok(())
+    pub fn set_fonts(&mut self, fonts: Vec<String>) {
+        self.fonts = fonts;
+        self.menus
+            .get_page_mut(FONTS_PAGE)
+            .set_items(get_fonts_menu_items(&self.fonts, &self.profile));
+        let name = self.words_font.clone();
+        self.select_words_font(&name);
     }
 
-    fn handle_input_key_in_settings_menu(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
-        match input_key_code {
-            keyboard::KeyCode::Up => self.settings_menu.handle_move_up(),
-            keyboard::KeyCode::Down => self.settings_menu.handle_move_down(),
-            keyboard::KeyCode::Left => self.current_menu_type = MenuType::Main,
-            keyboard::KeyCode::Space => {
-                let selected_option = self.settings_menu.get_selected_option();
-                if *selected_option == String::from("CHANGE FONT") {
-                    self.current_menu_type = MenuType::Fonts;
-                } else if *selected_option == String::from("CHANGE BG COLOR") {
-                    self.current_menu_type = MenuType::BgColors;
-                }
+    // falls back to the default font when the font was not loaded
+    fn select_words_font(&mut self, name: &str) {
+        let index = self.fonts.iter().position(|font| font == name).or_else(|| {
+            self.fonts
+                .iter()
+                .position(|font| font == DEFAULT_WORDS_FONT)
+        });
+        self.words_font = match index {
+            Some(index) => {
+                self.menus
+                    .get_page_mut(FONTS_PAGE)
+                    .menu
+                    .selected_option_index = index;
+                self.fonts[index].clone()
             }
-            _ => (),
+            None => String::from(self.get_font(DEFAULT_WORDS_FONT)),
         };
-
-        Ok(())
     }
 
-    fn handle_input_key_in_fonts_menu(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
-        match input_key_code {
-            keyboard::KeyCode::Up => self.fonts_menu.handle_move_up(),
-            keyboard::KeyCode::Down => self.fonts_menu.handle_move_down(),
-            keyboard::KeyCode::Left => self.current_menu_type = MenuType::Settings,
-            keyboard::KeyCode::Space => {
-                self.words_font = self.fonts_menu.get_selected_option().clone()
-            }
-            _ => (),
-        };
+    // the font to draw with, falls back to a font that is always there when it was not loaded
+    fn get_font<'a>(&'a self, name: &'a str) -> &'a str {
+        fonts::resolve_font(&self.fonts, name)
+    }
 
-        Ok(())
+    pub fn set_themes(&mut self, themes: Vec<Theme>) {
+        self.themes = themes;
+        self.menus
+            .get_page_mut(THEMES_PAGE)
+            .set_items(get_themes_menu_items(&self.themes, &self.profile));
+        let name = self.theme.name.clone();
+        self.select_theme(&name);
     }
 
-    fn handle_input_key_in_bg_colors_menu(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
-        match input_key_code {
-            keyboard::KeyCode::Up => self.bg_colors_menu.handle_move_up(),
-            keyboard::KeyCode::Down => self.bg_colors_menu.handle_move_down(),
-            keyboard::KeyCode::Left => self.current_menu_type = MenuType::Settings,
-            keyboard::KeyCode::Space => {
-                self.bg_color =
-                    get_color_by_label(self.bg_colors_menu.get_selected_option().clone())
+    // falls back to the default theme when there is no theme with the name
+    fn select_theme(&mut self, name: &str) {
+        let index = self
+            .themes
+            .iter()
+            .position(|theme| theme.name == name)
+            .or_else(|| {
+                self.themes
+                    .iter()
+                    .position(|theme| theme.name == DEFAULT_THEME_NAME)
+            });
+        self.theme = match index {
+            Some(index) => {
+                self.menus
+                    .get_page_mut(THEMES_PAGE)
+                    .menu
+                    .selected_option_index = index;
+                self.themes[index].clone()
             }
-            _ => (),
+            None => Theme::default(),
         };
-
-        Ok(())
     }
-}
 
-impl EventHandler for Game {
-    fn update(&mut self, ctx: &mut Context) -> GameResult {
-        if let Some(first_word) = self.words.front() {
-            if first_word.position.y >= self.screen_height {
-                if self.life_points > 0 {
-                    self.life_points -= 1;
-                    self.words.pop_front();
-                } else {
-                    self.end_game()?
-                }
-            }
+    // words keep their relative place so nothing jumps past the bottom or the right edge
+    pub fn resize(&mut self, width: f32, height: f32) {
+        if width <= 0.0 || height <= 0.0 {
+            return;
         }
-
-        if !self.is_game_running {
-            return Ok(());
+        let scale_x = width / self.screen_width;
+        let scale_y = height / self.screen_height;
+        self.screen_width = width;
+        self.screen_height = height;
+        let max_x = self.get_max_word_x();
+        for word in &mut self.words {
+            word.position.x = (word.position.x * scale_x).clamp(0.0, max_x.max(0.0));
+            word.position.y *= scale_y;
         }
+        for explosion in &mut self.damage_feedback.explosions {
+            explosion.position.x *= scale_x;
+            explosion.position.y *= scale_y;
+        }
+    }
 
-        let last_frame_length = ctx.time.delta().as_secs_f32();
-
-        if let Some(slow_down_time_left) = self.slow_down_time_left {
-            self.update_slow_down_time_left(slow_down_time_left, last_frame_length);
+    fn apply_display_mode(&mut self, ctx: &mut Context) {
+        if !self.is_display_mode_changed {
+            return;
+        }
+        self.is_display_mode_changed = false;
+        if let Err(err) = ctx
+            .gfx
+            .set_fullscreen(self.display_mode.get_fullscreen_type())
+        {
+            eprintln!("could not switch the display mode: {}", err);
         }
+    }
 
-        self.update_words_positions(self.game_speed as f32 * last_frame_length);
+    fn get_layout(&self) -> Layout {
+        Layout::new(
+            self.screen_width,
+            self.screen_height,
+            self.ui_scale,
+            self.word_size,
+        )
+    }
 
-        if let Some(time_until_next_word) = self.time_until_next_word {
-            if time_until_next_word <= 0.0 {
-                let mut new_word_limit = None;
-                if let Some(short_words_time_left) = self.spawn_only_short_words_time_left {
-                    if short_words_time_left <= 0.0 {
-                        self.spawn_only_short_words_time_left = None
-                    } else {
-                        new_word_limit = Some(3);
-                    }
-                }
-                self.spawn_new_word(new_word_limit);
-                self.time_until_next_word = Some(self.next_word_loop_length);
-            } else {
-                self.time_until_next_word = Some(time_until_next_word - last_frame_length);
+    // the theme picker previews the highlighted theme
+    fn get_theme(&self) -> &Theme {
+        if self.menus.is_open(THEMES_PAGE) {
+            let index = self.menus.get_page(THEMES_PAGE).menu.selected_option_index;
+            if let Some(theme) = self.themes.get(index) {
+                return theme;
             }
         }
+        &self.theme
+    }
 
-        if let Some(short_words_time_left) = self.spawn_only_short_words_time_left {
-            self.spawn_only_short_words_time_left = Some(short_words_time_left - last_frame_length);
-        }
+    pub fn set_achievements(&mut self, achievements: Achievements) {
+        self.achievements = achievements;
+    }
 
-        Ok(())
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = profile;
+        self.refresh_unlockable_menus();
     }
 
-    fn draw(&mut self, ctx: &mut Context) -> GameResult {
-        let mut canvas = graphics::Canvas::from_frame(ctx, self.bg_color);
-        if let Some(time_passed) = self.passed_time_since_game_end {
-            if time_passed < 4.0 {
-                self.draw_end_game_message(&mut canvas, ctx);
-                self.passed_time_since_game_end =
-                    Some(time_passed + ctx.time.delta().as_secs_f32());
-            } else {
-                self.passed_time_since_game_end = None
-            }
-        }
-        if !self.is_game_running {
-            self.draw_home_screen(&mut canvas, ctx);
-        } else {
-            self.draw_player_stats(&mut canvas);
-            self.draw_words(&mut canvas);
-        }
-        canvas.finish(ctx)
+    fn refresh_unlockable_menus(&mut self) {
+        self.menus
+            .get_page_mut(FONTS_PAGE)
+            .set_items(get_fonts_menu_items(&self.fonts, &self.profile));
+        self.menus
+            .get_page_mut(THEMES_PAGE)
+            .set_items(get_themes_menu_items(&self.themes, &self.profile));
+        self.menus
+            .get_page_mut(MODES_PAGE)
+            .set_items(get_modes_menu_items(&self.profile));
+        self.menus
+            .get_page_mut(DIFFICULTY_PAGE)
+            .set_items(get_difficulty_menu_items(&self.profile));
     }
 
-    fn key_down_event(
-        &mut self,
-        _ctx: &mut Context,
-        input: ggez::input::keyboard::KeyInput,
-        _repeated: bool,
-    ) -> GameResult {
-        if let Some(input_key_code) = input.keycode {
-            if self.is_game_running {
-                if let Some(current_word) = self.words.front_mut() {
-                    if let Some(typed_letter) = self.key_codes_map.get(&input_key_code) {
-                        current_word.handle_typed_letter(*typed_letter)
-                    }
+    // keeps the saved difficulty when the current one only comes from the command line
+    fn get_saved_preset_settings(&self) -> PresetSettings {
+        PresetSettings {
+            difficulty: self.saved_difficulty,
+            custom_preset: self.preset_settings.custom_preset,
+        }
+    }
 
-                    if current_word.is_completed() {
-                        self.complete_word();
-                        if self.next_word_loop_length > 0.2 {
-                            self.next_word_loop_length -= 0.0003;
-                        }
-                    }
-                }
-            } else {
-                match self.current_menu_type {
-                    MenuType::Main => self.handle_input_key_in_main_menu(input_key_code)?,
-                    MenuType::Settings => self.handle_input_key_in_settings_menu(input_key_code)?,
-                    MenuType::Fonts => self.handle_input_key_in_fonts_menu(input_key_code)?,
-                    MenuType::BgColors => {
-                        self.handle_input_key_in_bg_colors_menu(input_key_code)?
-                    }
-                    MenuType::None => (),
-                }
+    fn save_preset_settings(&self) {
+        if let Some(path) = &self.preset_settings_path {
+            if let Err(err) = self.get_saved_preset_settings().save(path) {
+                eprintln!("could not save the difficulty presets: {}", err);
             }
         }
-        return Ok(());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use ggez::conf::WindowMode;
+    pub fn set_effect_table(&mut self, effect_table: EffectTable) {
+        self.active_effects = ActiveEffects::from_table(&effect_table);
+        self.effect_table = effect_table;
+    }
 
-    use super::*;
+    pub fn set_campaign(&mut self, campaign: Campaign) {
+        self.menus
+            .get_page_mut(LEVELS_PAGE)
+            .set_items(get_levels_menu_items(Some(&campaign)));
+        self.campaign = Some(campaign);
+    }
 
-    fn create_game() -> Game {
-        let conf = Conf::new().window_mode(WindowMode {
-            width: 1200.0,
-            height: 1000.0,
-            ..Default::default()
-        });
-        Game::new(&conf)
+    pub fn enable_spectator(&mut self, port: u16) -> std::io::Result<()> {
+        self.spectator = Some(SpectatorServer::start(port)?);
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::ToggleSpectator),
+            get_spectator_option_label(true),
+        );
+        Ok(())
     }
 
-    #[test]
-    fn it_initializes_game_with_defautl_state() {
-        let game = create_game();
+    pub fn disable_spectator(&mut self) {
+        self.spectator = None;
+        self.menus.set_label(
+            &MenuTarget::Command(MenuCommand::ToggleSpectator),
+            get_spectator_option_label(false),
+        );
+    }
 
-        assert_eq!(game.screen_height, 1000.0);
-        assert_eq!(game.screen_width, 1200.0);
-        assert_eq!(game.bg_color, Color::new(0.19, 0.2, 0.45, 0.65));
-        assert_eq!(game.current_menu_type, MenuType::Main);
-        assert_eq!(game.main_menu, create_main_menu());
-        assert_eq!(game.settings_menu, create_settings_menu());
-        assert_eq!(game.fonts_menu, create_fonts_menu());
-        assert_eq!(game.bg_colors_menu, create_bg_colors_menu());
-        assert_eq!(game.is_game_running, false);
-        assert_eq!(game.words.len(), 0);
-        assert_eq!(game.next_word_loop_length, INITIAL_TIME_UNTIL_NEXT_WORD);
-        assert_eq!(game.current_score, 0);
-        assert_eq!(game.life_points, 0);
-        assert_eq!(game.game_speed, INITIAL_GAME_SPEED);
-        assert!(game.time_until_next_word.is_none());
-        assert!(game.game_speed_before_slow_down.is_none());
-        assert!(game.passed_time_since_game_end.is_none());
-        assert!(game.slow_down_time_left.is_none());
-        assert!(game.spawn_only_short_words_time_left.is_none());
+    fn toggle_spectator(&mut self) {
+        if self.spectator.is_some() {
+            self.disable_spectator();
+        } else if let Err(err) = self.enable_spectator(DEFAULT_SPECTATOR_PORT) {
+            eprintln!("could not start the spectator server: {}", err);
+        }
     }
 
-    #[test]
-    fn it_starts_the_game_correctly() {
-        let mut game = create_game();
+    fn broadcast(&self, event: SpectatorEvent) {
+        if let Some(spectator) = &self.spectator {
+            spectator.broadcast(&event);
+        }
+    }
 
-        let start_res = game.start_game();
-        assert!(start_res.is_ok());
+    fn create_snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            score: self.current_score,
+            life_points: self.life_points,
+            game_speed: self.get_current_game_speed(),
+            words: self.words.iter().map(WordSnapshot::from).collect(),
+        }
+    }
 
-        assert!(game.is_game_running);
-        assert_eq!(game.current_score, 0);
-        assert_eq!(game.life_points, 0);
-        assert_eq!(
-            game.time_until_next_word,
-            Some(INITIAL_TIME_UNTIL_NEXT_WORD)
-        );
-        assert_eq!(game.game_speed, INITIAL_GAME_SPEED);
-        assert_eq!(game.passed_time_since_game_end, None);
+    fn update_snapshot_timer(&mut self, last_frame_length: f32) {
+        if self.spectator.is_none() {
+            return;
+        }
+        self.time_until_next_snapshot -= last_frame_length;
+        if self.time_until_next_snapshot <= 0.0 {
+            self.broadcast(SpectatorEvent::Snapshot(self.create_snapshot()));
+            self.time_until_next_snapshot = SNAPSHOT_INTERVAL;
+        }
     }
 
-    #[test]
+    // plain pages only draw their menu, the rest add their own parts around it
+    fn draw_home_screen(&self, canvas: &mut Canvas, ctx: &Context) {
+        let layout = self.get_layout();
+        let menu = &self.menus.get_current_page().menu;
+        let font = self.get_font(UI_FONT);
+        match self.menus.get_current_id() {
+            MAIN_PAGE => {
+                self.draw_player_level(canvas, ctx);
+                menu.draw(canvas, ctx, &layout, self.get_theme(), font)
+            }
+            FONTS_PAGE => {
+                menu.draw_with_fonts(canvas, ctx, &layout, self.get_theme(), font, &self.fonts)
+            }
+            THEMES_PAGE => {
+                menu.draw(canvas, ctx, &layout, self.get_theme(), font);
+                self.draw_theme_preview(canvas);
+            }
+            RESULTS_PAGE => self.draw_results(canvas, ctx),
+            ACHIEVEMENTS_PAGE => self.draw_achievements(canvas, ctx),
+            BINDINGS_PAGE => {
+                menu.draw(canvas, ctx, &layout, self.get_theme(), font);
+                self.draw_bindings_message(canvas, ctx);
+            }
+            PAUSE_PAGE => self.draw_pause_menu(canvas, ctx),
+            _ => menu.draw(canvas, ctx, &layout, self.get_theme(), font),
+        }
+    }
+
+    // a sample of the in-game colours below the theme picker
+    fn draw_theme_preview(&self, canvas: &mut Canvas) {
+        let theme = self.get_theme();
+        let layout = self.get_layout();
+        let y = layout.from_bottom(150.0);
+        let samples = [
+            (String::from("SCORE: 120"), Color::from(theme.score)),
+            (
+                String::from("word"),
+                theme.get_word_color(None, &self.effect_table),
+            ),
+            (
+                WordEffect::DoubleScore.get_label(),
+                theme.get_effect_color(WordEffect::DoubleScore, &self.effect_table),
+            ),
+            (String::from("STREAK: 12"), Color::from(theme.streak)),
+        ];
+        for (idx, (sample, color)) in samples.into_iter().enumerate() {
+            let mut text = graphics::Text::new(sample);
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(30.0)));
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default().color(color).dest(Point2 {
+                    x: layout.ui(30.0) + (self.screen_width - layout.ui(60.0)) / 4.0 * idx as f32,
+                    y,
+                }),
+            );
+        }
+        for index in 0..3 {
+            canvas.draw(
+                &graphics::Quad,
+                graphics::DrawParam::default()
+                    .dest_rect(graphics::Rect::new(
+                        layout.ui(30.0 + index as f32 * LIFE_ICON_SPACING),
+                        y + layout.ui(60.0),
+                        layout.ui(LIFE_ICON_SIZE),
+                        layout.ui(LIFE_ICON_SIZE),
+                    ))
+                    .color(Color::from(theme.lives)),
+            );
+        }
+    }
+
+    fn draw_bindings_message(&self, canvas: &mut Canvas, ctx: &Context) {
+        let Some(message) = &self.bindings_message else {
+            return;
+        };
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new(message.as_str());
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(30.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().danger))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.from_bottom(120.0),
+                }),
+        );
+    }
+
+    fn draw_player_level(&self, canvas: &mut Canvas, ctx: &Context) {
+        let (level, xp, xp_needed) = self.profile.get_level_progress();
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new(format!("LEVEL {}", level));
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(40.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().text))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.ui(60.0),
+                }),
+        );
+
+        let bar_width = layout.ui(XP_BAR_WIDTH);
+        let bar_x = layout.center_x(bar_width);
+        canvas.draw(
+            &graphics::Quad,
+            graphics::DrawParam::default()
+                .dest_rect(graphics::Rect::new(
+                    bar_x,
+                    layout.ui(120.0),
+                    bar_width,
+                    layout.ui(XP_BAR_HEIGHT),
+                ))
+                .color(Color::from(self.get_theme().bar_background)),
+        );
+        canvas.draw(
+            &graphics::Quad,
+            graphics::DrawParam::default()
+                .dest_rect(graphics::Rect::new(
+                    bar_x,
+                    layout.ui(120.0),
+                    bar_width * xp as f32 / xp_needed as f32,
+                    layout.ui(XP_BAR_HEIGHT),
+                ))
+                .color(Color::from(self.get_theme().highlight)),
+        );
+    }
+
+    fn draw_player_stats(&self, canvas: &mut Canvas) {
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new(format!("SCORE: {}", self.current_score));
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(HUD_TEXT_SIZE)));
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().score))
+                .dest(Point2 {
+                    x: layout.ui(HUD_MARGIN),
+                    y: layout.from_bottom(100.0),
+                }),
+        );
+
+        if let Some(wave) = &self.wave {
+            let mut text = graphics::Text::new(format!("WAVE: {}", wave.wave_number));
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(HUD_TEXT_SIZE)));
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default()
+                    .color(Color::from(self.get_theme().wave))
+                    .dest(Point2 {
+                        x: layout.ui(HUD_MARGIN),
+                        y: layout.from_bottom(250.0),
+                    }),
+            );
+        }
+
+        self.draw_streak(canvas);
+
+        self.draw_lives(canvas);
+    }
+
+    // a filled icon for every life left and a faded one for every slot up to the maximum
+    fn draw_lives(&self, canvas: &mut Canvas) {
+        let layout = self.get_layout();
+        let y = layout.from_bottom(165.0);
+        let size = layout.ui(LIFE_ICON_SIZE);
+        for index in 0..self.rules.max_lives.max(self.life_points) {
+            let x = layout.ui(HUD_MARGIN + index as f32 * LIFE_ICON_SPACING);
+            let color = if index < self.life_points {
+                Color::from(self.get_theme().lives)
+            } else {
+                with_alpha(self.get_theme().lives, 0.2)
+            };
+            match self.damage_feedback.breaking_life {
+                Some((life_index, time_left)) if life_index == index => {
+                    self.draw_breaking_life(canvas, x, y, size, time_left)
+                }
+                _ => canvas.draw(
+                    &graphics::Quad,
+                    graphics::DrawParam::default()
+                        .dest_rect(graphics::Rect::new(x, y, size, size))
+                        .color(color),
+                ),
+            }
+        }
+    }
+
+    // the two halves of the lost icon fall apart and fade out
+    fn draw_breaking_life(&self, canvas: &mut Canvas, x: f32, y: f32, size: f32, time_left: f32) {
+        let progress = 1.0 - time_left / LIFE_BREAK_LENGTH;
+        let half_width = size / 2.0;
+        let color = with_alpha(self.get_theme().danger, 1.0 - progress);
+        for (offset_x, half_x) in [(-1.0, x), (1.0, x + half_width)] {
+            canvas.draw(
+                &graphics::Quad,
+                graphics::DrawParam::default()
+                    .dest_rect(graphics::Rect::new(
+                        half_x + offset_x * progress * size,
+                        y + progress * size,
+                        half_width,
+                        size,
+                    ))
+                    .color(color),
+            );
+        }
+    }
+
+    // the letters of every missed word fly apart from its centre
+    fn draw_explosions(&self, canvas: &mut Canvas) {
+        let layout = self.get_layout();
+        for explosion in &self.damage_feedback.explosions {
+            let progress = explosion.get_progress();
+            let letters: Vec<&str> = explosion.value.graphemes(true).collect();
+            let center = (letters.len() as f32 - 1.0) / 2.0;
+            for (idx, letter) in letters.iter().enumerate() {
+                let direction = idx as f32 - center;
+                let mut text = graphics::Text::new(*letter);
+                text.set_font(self.words_font.as_str());
+                text.set_scale(graphics::PxScale::from(layout.get_word_size()));
+                canvas.draw(
+                    &text,
+                    graphics::DrawParam::default()
+                        .color(with_alpha(self.get_theme().explosion, 1.0 - progress))
+                        .dest(Point2 {
+                            x: explosion.position.x
+                                + layout.word(idx as f32 * EXPLOSION_LETTER_WIDTH)
+                                + direction * progress * layout.word(EXPLOSION_SPREAD),
+                            y: explosion.position.y
+                                - (1.0 - direction.abs() / (center + 1.0))
+                                    * progress
+                                    * layout.word(EXPLOSION_SPREAD),
+                        })
+                        .rotation(direction * progress),
+                );
+            }
+        }
+    }
+
+    fn draw_damage_flash(&self, canvas: &mut Canvas) {
+        let alpha = self.damage_feedback.get_flash_alpha();
+        if alpha <= 0.0 {
+            return;
+        }
+        canvas.draw(
+            &graphics::Quad,
+            graphics::DrawParam::default()
+                .dest_rect(graphics::Rect::new(
+                    0.0,
+                    0.0,
+                    self.screen_width,
+                    self.screen_height,
+                ))
+                .color(with_alpha(
+                    self.get_theme().danger,
+                    alpha * DAMAGE_FLASH_OPACITY,
+                )),
+        );
+    }
+
+    fn draw_streak(&self, canvas: &mut Canvas) {
+        let layout = self.get_layout();
+        let y = if self.wave.is_some() {
+            layout.from_bottom(325.0)
+        } else {
+            layout.from_bottom(250.0)
+        };
+        if let Some((broken_streak, time_left)) = self.streak.broken {
+            // shakes sideways while it fades out
+            let shake = (time_left * 40.0).sin() * layout.ui(8.0) * time_left;
+            let mut text = graphics::Text::new(format!("STREAK LOST: {}", broken_streak));
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(40.0)));
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default()
+                    .color(with_alpha(
+                        self.get_theme().danger,
+                        time_left / STREAK_BREAK_ANIMATION_LENGTH,
+                    ))
+                    .dest(Point2 {
+                        x: layout.ui(HUD_MARGIN) + shake,
+                        y,
+                    }),
+            );
+        } else if self.streak.current > 0 {
+            let mut text = graphics::Text::new(format!(
+                "STREAK: {} X{}",
+                self.streak.current,
+                self.streak.get_multiplier()
+            ));
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(40.0)));
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default()
+                    .color(Color::from(self.get_theme().streak))
+                    .dest(Point2 {
+                        x: layout.ui(HUD_MARGIN),
+                        y,
+                    }),
+            );
+        }
+    }
+
+    // one row per active effect, stacked upwards from the bottom right corner
+    fn draw_active_effects(&self, canvas: &mut Canvas) {
+        let layout = self.get_layout();
+        let x = layout.from_right(EFFECTS_HUD_WIDTH + HUD_MARGIN);
+        let icon_size = layout.ui(EFFECT_ICON_SIZE);
+        let bar_height = layout.ui(EFFECT_BAR_HEIGHT);
+        for (index, active) in self.active_effects.iter().enumerate() {
+            let y = layout.from_bottom(80.0 + EFFECTS_HUD_ROW_HEIGHT * index as f32);
+            let color = self
+                .get_theme()
+                .get_effect_color(active.effect, &self.effect_table);
+
+            canvas.draw(
+                &graphics::Quad,
+                graphics::DrawParam::default()
+                    .dest_rect(graphics::Rect::new(x, y, icon_size, icon_size))
+                    .color(color),
+            );
+
+            let label = if active.stacks > 1 {
+                format!("{} X{}", active.effect.get_label(), active.stacks)
+            } else {
+                active.effect.get_label()
+            };
+            let mut text = graphics::Text::new(label);
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(22.0)));
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default().color(color).dest(Point2 {
+                    x: x + icon_size + layout.ui(10.0),
+                    y: y - layout.ui(4.0),
+                }),
+            );
+
+            let bar_x = x + icon_size + layout.ui(10.0);
+            let bar_y = y + icon_size - bar_height;
+            let bar_width = layout.ui(EFFECTS_HUD_WIDTH - EFFECT_ICON_SIZE - 10.0);
+            canvas.draw(
+                &graphics::Quad,
+                graphics::DrawParam::default()
+                    .dest_rect(graphics::Rect::new(bar_x, bar_y, bar_width, bar_height))
+                    .color(Color::from(self.get_theme().bar_background)),
+            );
+            canvas.draw(
+                &graphics::Quad,
+                graphics::DrawParam::default()
+                    .dest_rect(graphics::Rect::new(
+                        bar_x,
+                        bar_y,
+                        bar_width * active.get_remaining_fraction(),
+                        bar_height,
+                    ))
+                    .color(color),
+            );
+        }
+    }
+
+    // the banner pops in large and shrinks back while it fades out
+    fn draw_effect_banner(&self, canvas: &mut Canvas, ctx: &Context) {
+        let Some((effect, time_left)) = self.effect_banner else {
+            return;
+        };
+        let progress = 1.0 - time_left / EFFECT_BANNER_DURATION;
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new(format!("{}!", effect.get_label()));
+        let font = self.effect_table.get_font(Some(effect), UI_FONT);
+        text.set_font(self.get_font(&font));
+        text.set_scale(graphics::PxScale::from(
+            layout.ui(80.0 - 20.0 * progress.min(0.3) / 0.3),
+        ));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        let mut color = self
+            .get_theme()
+            .get_effect_color(effect, &self.effect_table);
+        color.a *= time_left / EFFECT_BANNER_DURATION;
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default().color(color).dest(Point2 {
+                x: layout.center_x(text_width),
+                y: layout.ui(150.0),
+            }),
+        );
+    }
+
+    fn update_effect_banner(&mut self, last_frame_length: f32) {
+        if let Some((effect, time_left)) = self.effect_banner {
+            self.effect_banner = if time_left - last_frame_length <= 0.0 {
+                None
+            } else {
+                Some((effect, time_left - last_frame_length))
+            };
+        }
+    }
+
+    fn draw_words(&self, canvas: &mut Canvas, ctx: &Context) {
+        let is_mirrored = self.active_effects.is_active(WordEffect::Mirror);
+        let word_size = self.get_layout().get_word_size();
+        for word in &self.words {
+            let value = if word.is_masked() {
+                word.get_masked_value(self.hide_typed_letters)
+            } else {
+                word.get_display_value()
+            };
+            let mut text = graphics::Text::new(value);
+            let font = self.effect_table.get_font(word.effect, &self.words_font);
+            text.set_font(self.get_font(&font));
+            text.set_scale(graphics::PxScale::from(word_size));
+            let mut draw_param = graphics::DrawParam::default()
+                .color(
+                    self.get_theme()
+                        .get_word_color(word.effect, &self.effect_table),
+                )
+                .dest(word.position);
+            if is_mirrored {
+                // flipping happens around the left edge, so shift the word back into place
+                let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+                draw_param = draw_param
+                    .scale([-1.0, 1.0])
+                    .dest([word.position.x + text_width, word.position.y]);
+            }
+            canvas.draw(&text, draw_param);
+        }
+    }
+
+    fn draw_fog(&self, canvas: &mut Canvas) {
+        canvas.draw(
+            &graphics::Quad,
+            graphics::DrawParam::default()
+                .dest_rect(graphics::Rect::new(
+                    0.0,
+                    0.0,
+                    self.screen_width,
+                    self.screen_height / 2.0,
+                ))
+                .color(Color::from(self.get_theme().fog)),
+        );
+    }
+
+    fn draw_intermission(&self, canvas: &mut Canvas, ctx: &Context, wave: &WaveState) {
+        let time_left = wave.intermission_time_left.unwrap_or(0.0).ceil();
+        let mut text = graphics::Text::new(format!(
+            "WAVE {} CLEARED - NEXT IN {}",
+            wave.wave_number, time_left
+        ));
+        let layout = self.get_layout();
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(40.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().highlight))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.ui(100.0),
+                }),
+        );
+        self.menus.get_page(SHOP_PAGE).menu.draw(
+            canvas,
+            ctx,
+            &layout,
+            self.get_theme(),
+            self.get_font(UI_FONT),
+        );
+    }
+
+    fn get_results_lines(&self) -> Vec<String> {
+        let Some(summary) = &self.last_summary else {
+            return Vec::new();
+        };
+        if let Some(offset) = self.results_details_offset {
+            let mut lines = vec![String::from("WORD SCORES")];
+            lines.extend(
+                summary
+                    .word_scores
+                    .iter()
+                    .skip(offset)
+                    .take(RESULTS_DETAILS_LINES)
+                    .map(|word_score| word_score.get_label()),
+            );
+            return lines;
+        }
+        let mut lines = Vec::from([
+            format!("MODE: {}", summary.mode),
+            format!("WORDS: {}", summary.completed_words),
+            format!("SCORE: {}", summary.score),
+            format!("BEST STREAK: {}", summary.best_streak),
+            format!("XP: +{}", summary.xp),
+        ]);
+        if summary.level.is_none() {
+            lines.insert(1, format!("DIFFICULTY: {}", summary.difficulty.get_label()));
+        }
+        if self.levels_gained > 0 {
+            lines.push(format!("LEVEL UP! LV {}", self.profile.get_level()));
+        }
+        match self.previous_best_score {
+            Some(best) if best >= summary.score => lines.push(format!("BEST: {}", best)),
+            _ => lines.push(String::from("NEW BEST!")),
+        }
+        if !summary.misremembered_words.is_empty() {
+            lines.push(String::from("MISREMEMBERED:"));
+            for chunk in summary.misremembered_words.chunks(4) {
+                lines.push(chunk.join(", ").to_uppercase());
+            }
+        }
+        if !summary.word_scores.is_empty() {
+            lines.push(format!(
+                "{}: WORD SCORES",
+                self.bindings.get_keys_label(Action::Details)
+            ));
+        }
+        lines
+    }
+
+    fn draw_results(&self, canvas: &mut Canvas, ctx: &Context) {
+        let layout = self.get_layout();
+        for (idx, line) in self.get_results_lines().iter().enumerate() {
+            let mut text = graphics::Text::new(line.as_str());
+            text.set_font(self.get_font(UI_FONT));
+            text.set_scale(graphics::PxScale::from(layout.ui(40.0)));
+            let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+            canvas.draw(
+                &text,
+                graphics::DrawParam::default()
+                    .color(Color::from(self.get_theme().text))
+                    .dest(Point2 {
+                        x: layout.center_x(text_width),
+                        y: layout.ui(250.0 + (60 * idx) as f32),
+                    }),
+            );
+        }
+    }
+
+    fn draw_achievements(&self, canvas: &mut Canvas, ctx: &Context) {
+        let layout = self.get_layout();
+        for (idx, achievement) in Achievement::ALL.iter().enumerate() {
+            let color = if self.achievements.is_unlocked(*achievement) {
+                Color::from(self.get_theme().highlight)
+            } else {
+                Color::from(self.get_theme().dimmed)
+            };
+            let lines = [
+                (achievement.get_label(), 50.0),
+                (achievement.get_description(), 25.0),
+            ];
+            for (line_idx, (line, scale)) in lines.into_iter().enumerate() {
+                let mut text = graphics::Text::new(line);
+                text.set_font(self.get_font(UI_FONT));
+                text.set_scale(graphics::PxScale::from(layout.ui(scale)));
+                let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+                canvas.draw(
+                    &text,
+                    graphics::DrawParam::default().color(color).dest(Point2 {
+                        x: layout.center_x(text_width),
+                        y: layout.ui(200.0 + (130 * idx) as f32 + (60 * line_idx) as f32),
+                    }),
+                );
+            }
+        }
+    }
+
+    fn draw_achievement_toast(&self, canvas: &mut Canvas, ctx: &Context) {
+        let Some((achievement, time_left)) = self.achievement_toasts.front() else {
+            return;
+        };
+        let mut text =
+            graphics::Text::new(format!("ACHIEVEMENT UNLOCKED: {}", achievement.get_label()));
+        let layout = self.get_layout();
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(35.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(with_alpha(self.get_theme().toast, time_left.min(1.0)))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.ui(40.0),
+                }),
+        );
+    }
+
+    fn draw_pause_menu(&self, canvas: &mut Canvas, ctx: &Context) {
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new("PAUSED");
+        text.set_font(self.get_font(UI_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(80.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().text))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.ui(80.0),
+                }),
+        );
+        self.menus.get_page(PAUSE_PAGE).menu.draw(
+            canvas,
+            ctx,
+            &layout,
+            self.get_theme(),
+            self.get_font(UI_FONT),
+        );
+    }
+
+    fn draw_pause_overlay(&self, canvas: &mut Canvas) {
+        canvas.draw(
+            &graphics::Quad,
+            graphics::DrawParam::default()
+                .dest_rect(graphics::Rect::new(
+                    0.0,
+                    0.0,
+                    self.screen_width,
+                    self.screen_height,
+                ))
+                .color(Color::from(self.get_theme().overlay)),
+        );
+    }
+
+    fn draw_end_game_message(&self, canvas: &mut Canvas, ctx: &Context) {
+        let layout = self.get_layout();
+        let mut text = graphics::Text::new(self.end_game_message.as_str());
+        text.set_font(self.get_font(DANGER_FONT));
+        text.set_scale(graphics::PxScale::from(layout.ui(100.0)));
+        let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+        canvas.draw(
+            &text,
+            graphics::DrawParam::default()
+                .color(Color::from(self.get_theme().danger))
+                .dest(Point2 {
+                    x: layout.center_x(text_width),
+                    y: layout.ui(100.0),
+                }),
+        );
+    }
+
+    fn start_game(&mut self) -> GameResult {
+        if let Some(seed) = self.seed {
+            self.rng = StdRng::seed_from_u64(seed);
+        }
+        self.is_game_running = true;
+        self.is_paused = false;
+        self.words.clear();
+        self.active_effects.reset();
+        self.effect_banner = None;
+        self.current_score = 0;
+        self.life_points = self.rules.starting_lives;
+        self.damage_feedback.clear();
+        self.completed_words = 0;
+        self.streak = Streak::default();
+        self.misremembered_words.clear();
+        self.word_scores.clear();
+        self.run_stats = RunStats::default();
+        self.achievement_toasts.clear();
+        self.achievements.register_play_day(get_current_day());
+        if let Err(err) = self.achievements.save() {
+            eprintln!("could not save the achievements: {}", err);
+        }
+        self.check_achievements();
+        self.time_until_next_word = Some(self.rules.initial_spawn_interval);
+        self.next_word_loop_length = self.rules.initial_spawn_interval;
+        self.base_game_speed = self.rules.initial_game_speed;
+        self.game_speed = self.rules.initial_game_speed;
+        self.effect_spawn_chance = self.rules.effect_chance;
+        self.passed_time_since_game_end = None;
+        self.wave = match self.game_mode {
+            GameMode::Waves => Some(WaveState::new()),
+            GameMode::Endless | GameMode::Campaign | GameMode::Blind => None,
+        };
+        self.broadcast(SpectatorEvent::GameStarted);
+
+        Ok(())
+    }
+
+    fn pause_game(&mut self) {
+        if !self.is_game_running || self.is_paused {
+            return;
+        }
+        self.is_paused = true;
+        self.menus
+            .get_page_mut(PAUSE_PAGE)
+            .menu
+            .selected_option_index = 0;
+        self.menus.reset(PAUSE_PAGE);
+    }
+
+    // the shop stays open when the game is paused during an intermission
+    fn resume_game(&mut self) {
+        self.is_paused = false;
+        if self
+            .wave
+            .as_ref()
+            .is_some_and(|wave| wave.is_in_intermission())
+        {
+            self.menus.reset(SHOP_PAGE);
+        } else {
+            self.menus.reset(MAIN_PAGE);
+        }
+    }
+
+    fn restart_game(&mut self) -> GameResult {
+        match self.current_level_index {
+            Some(level_index) => self.start_level(level_index),
+            None => self.start_game(),
+        }
+    }
+
+    fn quit_to_menu(&mut self) {
+        self.is_game_running = false;
+        self.is_paused = false;
+        self.words.clear();
+        self.wave = None;
+        self.menus.reset(MAIN_PAGE);
+        self.broadcast(SpectatorEvent::GameOver {
+            score: self.current_score,
+        });
+    }
+
+    fn reset_rules(&mut self) {
+        self.rules = self.preset_settings.get_preset().get_rules();
+        self.source_words = self.get_default_source_words();
+        self.allowed_effects = Vec::from(WordEffect::ALL);
+        self.motion_patterns = self.game_mode.get_motion_patterns();
+        self.active_effects = ActiveEffects::from_table(&self.effect_table);
+        self.current_level_index = None;
+    }
+
+    fn get_default_source_words(&self) -> Vec<String> {
+        self.custom_source_words
+            .clone()
+            .unwrap_or_else(get_default_source_words)
+    }
+
+    fn start_level(&mut self, level_index: usize) -> GameResult {
+        let Some(campaign) = &self.campaign else {
+            return Ok(());
+        };
+        if !campaign.is_unlocked(level_index) {
+            return Ok(());
+        }
+        let level = &campaign.levels[level_index];
+        self.rules = level.get_rules();
+        self.source_words = if level.words.is_empty() {
+            self.get_default_source_words()
+        } else {
+            level.words.clone()
+        };
+        self.allowed_effects = level.allowed_effects.clone();
+        self.motion_patterns = level.motion_patterns.clone();
+        self.active_effects = ActiveEffects::from_table(&self.effect_table);
+        for (&effect, &duration) in &level.effect_durations {
+            self.active_effects.set_duration(effect, duration);
+        }
+        self.current_level_index = Some(level_index);
+        self.start_game()
+    }
+
+    fn check_level_completion(&mut self) -> GameResult {
+        let (Some(campaign), Some(level_index)) = (&mut self.campaign, self.current_level_index)
+        else {
+            return Ok(());
+        };
+        let level = &campaign.levels[level_index];
+        if !level.win.is_met(self.completed_words, self.current_score) {
+            return Ok(());
+        }
+
+        let stars = level.count_stars(self.current_score);
+        campaign.progress.record_level_completion(level_index, stars);
+        if let Err(err) = campaign.save_progress() {
+            eprintln!("could not save the campaign progress: {}", err);
+        }
+        let items = get_levels_menu_items(Some(campaign));
+        self.menus.get_page_mut(LEVELS_PAGE).set_items(items);
+        self.end_game_message = format!("LEVEL COMPLETE - {}/3 STARS", stars);
+        self.end_game()
+    }
+
+    fn record_game_summary(&mut self) {
+        let level = match (&self.campaign, self.current_level_index) {
+            (Some(campaign), Some(level_index)) => Some(campaign.levels[level_index].name.clone()),
+            _ => None,
+        };
+        let mode = match &level {
+            Some(name) => format!("{} {}", self.game_mode.get_label(), name.to_uppercase()),
+            None => self.game_mode.get_label(),
+        };
+        let summary = GameSummary {
+            mode,
+            level,
+            difficulty: self.preset_settings.difficulty,
+            preset: self.preset_settings.get_preset(),
+            score: self.current_score,
+            completed_words: self.completed_words,
+            best_streak: self.streak.best,
+            misremembered_words: self.misremembered_words.clone(),
+            word_scores: self.word_scores.clone(),
+            xp: progression::calculate_xp(
+                self.current_score,
+                self.run_stats.get_accuracy(),
+                self.game_mode,
+            ),
+        };
+        self.levels_gained = self.profile.add_xp(summary.xp);
+        if self.levels_gained > 0 {
+            self.refresh_unlockable_menus();
+        }
+        self.previous_best_score = self.results.get_best_score(&summary);
+        self.results.add(summary.clone());
+        self.last_summary = Some(summary);
+    }
+
+    fn end_game(&mut self) -> GameResult {
+        self.record_game_summary();
+        self.menus.reset(MAIN_PAGE);
+        self.menus.open(RESULTS_PAGE);
+        self.is_game_running = false;
+        self.words.clear();
+        self.wave = None;
+        self.passed_time_since_game_end = Some(0.0);
+        self.broadcast(SpectatorEvent::GameOver {
+            score: self.current_score,
+        });
+        Ok(())
+    }
+
+    // leaves room for the word to fit on screen
+    fn get_max_word_x(&self) -> f32 {
+        self.screen_width - self.get_layout().word(WORD_ROOM)
+    }
+
+    fn update_words_positions(&mut self, delta_y: f32, last_frame_length: f32) {
+        let max_x = self.get_max_word_x();
+        for word in &mut self.words {
+            word.update_motion(delta_y, last_frame_length, max_x)
+        }
+    }
+
+    fn spawn_new_word(&mut self, length_limit: Option<usize>) {
+        let mut source_words: Vec<&String> = self.source_words.iter().collect();
+        if let Some(limit) = length_limit {
+            let short_words: Vec<&String> = source_words
+                .iter()
+                .filter(|&word| word.graphemes(true).count() <= limit)
+                .copied()
+                .collect();
+            if !short_words.is_empty() {
+                source_words = short_words;
+            }
+        }
+        let Some(word) = source_words.choose(&mut self.rng) else {
+            return;
+        };
+        let (motion, spawn_x) =
+            Motion::new_random(&self.motion_patterns, self.get_max_word_x(), &mut self.rng);
+        let word_position = Point2 { x: spawn_x, y: 0.0 };
+        let (hazards, power_ups): (Vec<WordEffect>, Vec<WordEffect>) = self
+            .allowed_effects
+            .iter()
+            .partition(|effect| effect.is_hazard());
+        let difficulty = self.preset_settings.difficulty;
+        let effect = self
+            .effect_table
+            .roll_effect(
+                self.rules.hazard_chance,
+                &hazards,
+                self.current_score,
+                difficulty,
+                &mut self.rng,
+            )
+            .or_else(|| {
+                self.effect_table.roll_effect(
+                    self.effect_spawn_chance,
+                    &power_ups,
+                    self.current_score,
+                    difficulty,
+                    &mut self.rng,
+                )
+            });
+        let mut word = Word::new_with_effect(word, word_position, 0, effect);
+        word.motion = motion;
+        if self.game_mode == GameMode::Blind {
+            word.visibility = WordVisibility::Revealed {
+                time_left: BLIND_REVEAL_TIME,
+            };
+        }
+        self.broadcast(SpectatorEvent::WordSpawned {
+            word: WordSnapshot::from(&word),
+        });
+        self.words.push_back(word);
+    }
+
+    fn get_word_score(&self, word: &Word) -> WordScore {
+        let mut score = WordScore::new(
+            &word.value,
+            self.rules.word_score,
+            word.age,
+            word.position.y / self.screen_height,
+        );
+        if self.game_mode == GameMode::Blind {
+            if word.mistakes == 0 {
+                score.multiply(BLIND_PERFECT_WORD_MULTIPLIER);
+            } else {
+                score.scale(word.get_accuracy());
+            }
+        }
+        score
+    }
+
+    fn record_misremembered_word(&mut self, word: String) {
+        if self.game_mode == GameMode::Blind && !self.misremembered_words.contains(&word) {
+            self.misremembered_words.push(word);
+        }
+    }
+
+    fn update_words_timers(&mut self, last_frame_length: f32) {
+        for word in &mut self.words {
+            word.age += last_frame_length;
+            word.update_visibility(last_frame_length);
+        }
+    }
+
+    fn complete_word(&mut self) {
+        let word = self.words.pop_front().unwrap();
+        self.streak.register_completed_word();
+        let mut word_score = self.get_word_score(&word);
+        word_score.multiply(self.get_score_multiplier() * self.streak.get_multiplier());
+        if word.effect.is_some_and(|effect| effect.is_hazard()) {
+            word_score.multiply(HAZARD_SCORE_MULTIPLIER);
+        }
+        self.current_score += word_score.total;
+        let points = word_score.total;
+        self.word_scores.push(word_score);
+        if word.mistakes > 0 {
+            self.record_misremembered_word(word.value.clone());
+        }
+        self.game_speed = self.rules.get_increased_speed(self.game_speed);
+        self.completed_words += 1;
+        self.run_stats
+            .register_completed_word(word.value.graphemes(true).count());
+        self.broadcast(SpectatorEvent::WordCompleted {
+            word: word.value.clone(),
+            score: points,
+        });
+        match word.effect {
+            Some(WordEffect::Split) => self.split_word(&word),
+            Some(effect) if effect.get_hazard_trigger() == Some(HazardTrigger::Missed) => (),
+            Some(effect) => self.apply_word_effect(effect),
+            None => (),
+        }
+        self.check_achievements();
+    }
+
+    fn apply_word_effect(&mut self, effect: WordEffect) {
+        if !effect.is_hazard() {
+            self.run_stats.collected_power_ups += 1;
+        }
+        self.broadcast(SpectatorEvent::EffectActivated { effect });
+        self.effect_banner = Some((effect, EFFECT_BANNER_DURATION));
+        match effect {
+            WordEffect::AddLife => self.gain_life(),
+            WordEffect::Bomb => self.detonate_bomb(),
+            WordEffect::Scramble => self.scramble_words(),
+            WordEffect::SlowDown
+            | WordEffect::SpawnOnlyShortWords
+            | WordEffect::Freeze
+            | WordEffect::DoubleScore
+            | WordEffect::Shield
+            | WordEffect::SpeedBurst
+            | WordEffect::Fog
+            | WordEffect::Mirror
+            | WordEffect::Split => (),
+        }
+        self.active_effects.apply(effect);
+    }
+
+    // clears the screen, every destroyed word is worth part of its usual score
+    fn detonate_bomb(&mut self) {
+        let score_percent = self
+            .effect_table
+            .get_value(WordEffect::Bomb, BOMB_SCORE_PERCENT);
+        let destroyed_words_score: u32 = self
+            .words
+            .iter()
+            .map(|word| self.get_word_score(word).total * score_percent / 100)
+            .sum();
+        self.current_score += destroyed_words_score * self.get_score_multiplier();
+        self.words.clear();
+    }
+
+    fn scramble_words(&mut self) {
+        for word in &mut self.words {
+            word.scramble(&mut self.rng);
+        }
+    }
+
+    // the completed word comes back as two plain words made of its halves
+    fn split_word(&mut self, word: &Word) {
+        self.broadcast(SpectatorEvent::EffectActivated {
+            effect: WordEffect::Split,
+        });
+        self.effect_banner = Some((WordEffect::Split, EFFECT_BANNER_DURATION));
+        let Some((first_half, second_half)) = word.get_split_values() else {
+            return;
+        };
+        let max_x = self.get_max_word_x();
+        let offset = self.get_layout().word(SPLIT_WORD_OFFSET);
+        // the halves are typed next, first half first, ahead of any word still falling
+        for (value, offset) in [(second_half, offset), (first_half, -offset)] {
+            let position = Point2 {
+                x: (word.position.x + offset).clamp(0.0, max_x),
+                y: word.position.y,
+            };
+            let mut half = Word::new_with_effect(&value, position, 0, None);
+            half.visibility = word.visibility;
+            self.words.push_front(half);
+        }
+    }
+
+    fn get_score_multiplier(&self) -> u32 {
+        if self.active_effects.is_active(WordEffect::DoubleScore) {
+            self.effect_table
+                .get_value(WordEffect::DoubleScore, DOUBLE_SCORE_MULTIPLIER)
+        } else {
+            1
+        }
+    }
+
+    // timed effects only modify the speed while they are active, so the underlying game speed
+    // keeps growing and is never lost when they overlap
+    fn get_current_game_speed(&self) -> u32 {
+        if self.active_effects.is_active(WordEffect::Freeze) {
+            return 0;
+        }
+        let game_speed = if self.active_effects.is_active(WordEffect::SlowDown) {
+            let slow_down_speed = self
+                .effect_table
+                .get_value(WordEffect::SlowDown, SLOW_DOWN_GAME_SPEED);
+            self.game_speed.min(slow_down_speed)
+        } else {
+            self.game_speed
+        };
+        if self.active_effects.is_active(WordEffect::SpeedBurst) {
+            game_speed
+                * self
+                    .effect_table
+                    .get_value(WordEffect::SpeedBurst, SPEED_BURST_PERCENT)
+                / 100
+        } else {
+            game_speed
+        }
+    }
+
+    fn get_spawned_word_length_limit(&self) -> Option<usize> {
+        if self
+            .active_effects
+            .is_active(WordEffect::SpawnOnlyShortWords)
+        {
+            let length_limit = self
+                .effect_table
+                .get_value(WordEffect::SpawnOnlyShortWords, SHORT_WORD_LENGTH_LIMIT);
+            Some(length_limit as usize)
+        } else {
+            None
+        }
+    }
+
+    // faster words can overtake the front one, so every word is checked and the one being typed
+    // stays at the front
+    fn handle_fallen_words(&mut self) -> GameResult {
+        while let Some(index) = self
+            .words
+            .iter()
+            .position(|word| word.position.y >= self.screen_height)
+        {
+            self.handle_missed_word(index)?
+        }
+
+        Ok(())
+    }
+
+    fn handle_missed_word(&mut self, index: usize) -> GameResult {
+        let Some(missed_word) = self.words.get(index) else {
+            return Ok(());
+        };
+        let (missed_value, missed_effect) = (missed_word.value.clone(), missed_word.effect);
+        self.record_misremembered_word(missed_value);
+        self.streak.break_streak();
+        self.run_stats.mistakes += 1;
+        if let Some(hazard) = missed_effect
+            .filter(|effect| effect.get_hazard_trigger() == Some(HazardTrigger::Missed))
+        {
+            self.apply_word_effect(hazard);
+        }
+        if self.active_effects.consume(WordEffect::Shield) {
+            self.explode_missed_word(index);
+        } else if self.life_points > 0 {
+            self.life_points -= 1;
+            self.damage_feedback.lose_life(self.life_points);
+            self.explode_missed_word(index);
+            self.broadcast(SpectatorEvent::LifeLost {
+                life_points: self.life_points,
+            });
+        } else {
+            self.end_game_message = String::from("YOU LOST");
+            self.end_game()?
+        }
+
+        Ok(())
+    }
+
+    fn explode_missed_word(&mut self, index: usize) {
+        if let Some(word) = self.words.remove(index) {
+            self.damage_feedback
+                .explode_word(&word.get_display_value(), word.position);
+        }
+    }
+
+    fn gain_life(&mut self) {
+        self.life_points = (self.life_points + 1).min(self.rules.max_lives);
+    }
+
+    fn handle_typed_letter(&mut self, typed_letter: char) -> GameResult {
+        let Some(current_word) = self.words.front_mut() else {
+            return Ok(());
+        };
+        let mistakes_before = current_word.mistakes;
+        current_word.handle_typed_letter(typed_letter);
+        let made_mistake = current_word.mistakes > mistakes_before;
+        let is_completed = current_word.is_completed();
+        if let Some(spectator) = &self.spectator {
+            spectator.broadcast(&SpectatorEvent::LetterTyped {
+                letter: typed_letter,
+                word: current_word.value.clone(),
+                progress_index: current_word.progress_index,
+            });
+        }
+
+        if made_mistake {
+            self.streak.break_streak();
+            self.run_stats.mistakes += 1;
+        }
+        if is_completed {
+            self.complete_word();
+            self.next_word_loop_length =
+                self.rules.get_decreased_spawn_interval(self.next_word_loop_length);
+            self.check_level_completion()?;
+        }
+
+        Ok(())
+    }
+
+    fn check_achievements(&mut self) {
+        self.run_stats.score = self.current_score;
+        for achievement in self.achievements.check(&self.run_stats) {
+            self.achievement_toasts
+                .push_back((achievement, ACHIEVEMENT_TOAST_DURATION));
+        }
+    }
+
+    fn update_achievement_toasts(&mut self, last_frame_length: f32) {
+        if let Some((_, time_left)) = self.achievement_toasts.front_mut() {
+            *time_left -= last_frame_length;
+            if *time_left <= 0.0 {
+                self.achievement_toasts.pop_front();
+            }
+        }
+    }
+
+    fn can_spawn_word(&self) -> bool {
+        match &self.wave {
+            Some(wave) => wave.can_spawn_word(),
+            None => true,
+        }
+    }
+
+    fn start_intermission(&mut self) {
+        self.active_effects.clear();
+        if let Some(wave) = &mut self.wave {
+            wave.start_intermission();
+            self.menus
+                .get_page_mut(SHOP_PAGE)
+                .menu
+                .selected_option_index = 0;
+            self.menus.reset(SHOP_PAGE);
+        }
+    }
+
+    fn start_next_wave(&mut self) {
+        if let Some(wave) = &mut self.wave {
+            wave.start_next_wave();
+            self.game_speed = self.base_game_speed + WAVE_SPEED_INCREASE * (wave.wave_number - 1);
+            self.time_until_next_word = Some(self.rules.initial_spawn_interval);
+            self.menus.reset(MAIN_PAGE);
+        }
+    }
+
+    fn buy_upgrade(&mut self, upgrade: Upgrade) {
+        if self.current_score < upgrade.cost() {
+            return;
+        }
+        self.current_score -= upgrade.cost();
+        match upgrade {
+            Upgrade::ExtraLife => self.gain_life(),
+            Upgrade::SlowerBaseSpeed => {
+                self.base_game_speed = self
+                    .base_game_speed
+                    .saturating_sub(SLOWER_BASE_SPEED_UPGRADE)
+                    .max(MIN_BASE_GAME_SPEED)
+            }
+            Upgrade::LongerPowerUps => self
+                .active_effects
+                .add_bonus_duration(LONGER_POWER_UPS_UPGRADE),
+            Upgrade::HigherEffectChance => {
+                self.effect_spawn_chance =
+                    (self.effect_spawn_chance + HIGHER_EFFECT_CHANCE_UPGRADE).min(MAX_EFFECT_CHANCE)
+            }
+        }
+    }
+
+    // the word scores on the results screen scroll with the menu keys, returns whether the
+    // action was used for them
+    fn handle_word_scores_action(&mut self, action: Option<Action>) -> bool {
+        let word_scores_count = self
+            .last_summary
+            .as_ref()
+            .map_or(0, |summary| summary.word_scores.len());
+        match (action, self.results_details_offset) {
+            (Some(Action::Details), None) if word_scores_count > 0 => {
+                self.results_details_offset = Some(0)
+            }
+            (Some(Action::MenuDown), Some(offset)) => {
+                let max_offset = word_scores_count.saturating_sub(RESULTS_DETAILS_LINES);
+                self.results_details_offset = Some((offset + 1).min(max_offset));
+            }
+            (Some(Action::MenuUp), Some(offset)) => {
+                self.results_details_offset = Some(offset.saturating_sub(1))
+            }
+            (Some(Action::Back), Some(_)) => self.results_details_offset = None,
+            _ => return false,
+        }
+        true
+    }
+
+    fn handle_input_key_in_menu(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
+        // while waiting, any key is the new binding, even one that normally navigates
+        if let Some(action) = self.rebinding_action.take() {
+            self.bindings_message = match self.bindings.bind(action, input_key_code) {
+                Ok(()) => {
+                    self.save_settings();
+                    None
+                }
+                Err(err) => Some(err.get_message(input_key_code)),
+            };
+            self.menus
+                .get_page_mut(BINDINGS_PAGE)
+                .set_items(get_bindings_menu_items(&self.bindings));
+            return Ok(());
+        }
+        let action = self.bindings.get_menu_action(input_key_code);
+        if self.menus.is_open(RESULTS_PAGE) && self.handle_word_scores_action(action) {
+            return Ok(());
+        }
+        let command = match action {
+            Some(Action::MenuUp) => {
+                self.menus.navigate(MenuMove::Up);
+                None
+            }
+            Some(Action::MenuDown) => {
+                self.menus.navigate(MenuMove::Down);
+                None
+            }
+            Some(Action::Back) => self.menus.back(),
+            Some(Action::Select) => self.menus.select(),
+            _ => None,
+        };
+        match command {
+            Some(command) => self.run_menu_command(command),
+            None => Ok(()),
+        }
+    }
+
+    fn run_menu_command(&mut self, command: MenuCommand) -> GameResult {
+        match command {
+            MenuCommand::Play => {
+                if self.game_mode == GameMode::Campaign {
+                    self.menus.open(LEVELS_PAGE)
+                } else {
+                    self.reset_rules();
+                    self.start_game()?
+                }
+            }
+            MenuCommand::Resume => self.resume_game(),
+            MenuCommand::Restart => {
+                self.restart_game()?;
+                self.menus.reset(MAIN_PAGE);
+            }
+            MenuCommand::QuitToMenu => self.quit_to_menu(),
+            MenuCommand::ToggleSpectator => {
+                self.toggle_spectator();
+                self.spectator_setting = self.spectator.is_some();
+                self.save_settings();
+            }
+            MenuCommand::ToggleHideTypedLetters => {
+                self.hide_typed_letters = !self.hide_typed_letters;
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    get_hide_typed_letters_option_label(self.hide_typed_letters),
+                );
+                self.save_settings();
+            }
+            MenuCommand::CycleUiScale => {
+                self.ui_scale = get_next_option(&UI_SCALES, self.ui_scale);
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    get_ui_scale_option_label(self.ui_scale),
+                );
+                self.save_settings();
+            }
+            MenuCommand::CycleWordSize => {
+                self.word_size = get_next_option(&WORD_SIZES, self.word_size);
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    get_word_size_option_label(self.word_size),
+                );
+                self.save_settings();
+            }
+            MenuCommand::CycleDisplayMode => {
+                self.display_mode = self.display_mode.next();
+                self.saved_display_mode = self.display_mode;
+                self.is_display_mode_changed = true;
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    get_display_mode_option_label(self.display_mode),
+                );
+                self.save_settings();
+            }
+            MenuCommand::SelectFont(index) => {
+                let Some(font) = self.fonts.get(index) else {
+                    return Ok(());
+                };
+                if self.profile.is_font_unlocked(font) {
+                    self.words_font = font.clone();
+                    self.save_settings();
+                }
+            }
+            MenuCommand::SelectTheme(index) => {
+                let Some(theme) = self.themes.get(index) else {
+                    return Ok(());
+                };
+                if self.profile.is_theme_unlocked(&theme.name) {
+                    self.theme = theme.clone();
+                    self.save_settings();
+                }
+            }
+            MenuCommand::SelectMode(mode) => {
+                self.set_game_mode(mode);
+            }
+            MenuCommand::SelectDifficulty(difficulty) => {
+                if self.set_difficulty(difficulty) {
+                    self.saved_difficulty = difficulty;
+                    self.save_preset_settings();
+                }
+            }
+            MenuCommand::CyclePresetField(field) => {
+                field.cycle(&mut self.preset_settings.custom_preset);
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    field.get_label(&self.preset_settings.custom_preset),
+                );
+            }
+            MenuCommand::StartLevel(level_index) => self.start_level(level_index)?,
+            MenuCommand::Rebind(action) => {
+                self.rebinding_action = Some(action);
+                self.bindings_message = None;
+                self.menus.set_label(
+                    &MenuTarget::Command(command),
+                    format!("{}: PRESS A KEY", action.get_label()),
+                );
+            }
+            MenuCommand::BuyUpgrade(upgrade) => self.buy_upgrade(upgrade),
+            MenuCommand::StartNextWave => self.start_next_wave(),
+            MenuCommand::ResetBindings => {
+                self.bindings = Bindings::default();
+                self.bindings_message = None;
+                self.menus
+                    .get_page_mut(BINDINGS_PAGE)
+                    .set_items(get_bindings_menu_items(&self.bindings));
+                self.save_settings();
+            }
+            MenuCommand::SavePresetSettings => self.save_preset_settings(),
+            MenuCommand::DropThemePreview => {
+                // leaving without choosing drops the preview
+                let name = self.theme.name.clone();
+                self.select_theme(&name);
+            }
+            MenuCommand::ClearBindingsMessage => self.bindings_message = None,
+            MenuCommand::HideWordScores => self.results_details_offset = None,
+        }
+
+        Ok(())
+    }
+
+    fn handle_game_key(&mut self, input_key_code: VirtualKeyCode) -> GameResult {
+        let is_pause_key = self.bindings.get_game_action(input_key_code) == Some(Action::Pause);
+        if !self.is_game_running {
+            return self.handle_input_key_in_menu(input_key_code);
+        }
+        if self.is_paused {
+            // the pause key also closes the pause menu when it is bound to a different key
+            if is_pause_key && self.menus.is_open(PAUSE_PAGE) {
+                self.resume_game();
+                return Ok(());
+            }
+            return self.handle_input_key_in_menu(input_key_code);
+        }
+        if is_pause_key {
+            self.pause_game();
+            return Ok(());
+        }
+        if self.wave.as_ref().is_some_and(|wave| wave.is_in_intermission()) {
+            return self.handle_input_key_in_menu(input_key_code);
+        }
+        if let Some(&typed_letter) = self.key_codes_map.get(&input_key_code) {
+            self.handle_typed_letter(typed_letter)?;
+        }
+        Ok(())
+    }
+}
+
+impl EventHandler for Game {
+    fn update(&mut self, ctx: &mut Context) -> GameResult {
+        self.apply_display_mode(ctx);
+        if self.is_paused {
+            return Ok(());
+        }
+
+        self.handle_fallen_words()?;
+
+        if !self.is_game_running {
+            return Ok(());
+        }
+
+        let last_frame_length = ctx.time.delta().as_secs_f32();
+        self.run_stats.elapsed_time += last_frame_length;
+        self.update_achievement_toasts(last_frame_length);
+
+        if let Some(wave) = &mut self.wave {
+            if wave.is_in_intermission() {
+                if wave.update_intermission(last_frame_length) {
+                    self.start_next_wave();
+                }
+                return Ok(());
+            }
+        }
+
+        self.active_effects.tick(last_frame_length);
+        self.update_effect_banner(last_frame_length);
+        self.streak.update_break_animation(last_frame_length);
+        self.damage_feedback.update(last_frame_length);
+
+        if !self.active_effects.is_active(WordEffect::Freeze) {
+            let fall_distance = self
+                .get_layout()
+                .get_fall_distance(self.get_current_game_speed() as f32, last_frame_length);
+            self.update_words_positions(fall_distance, last_frame_length);
+        }
+        self.update_words_timers(last_frame_length);
+        self.update_snapshot_timer(last_frame_length);
+
+        if let Some(time_until_next_word) = self.time_until_next_word {
+            if time_until_next_word <= 0.0 {
+                if self.can_spawn_word() {
+                    self.spawn_new_word(self.get_spawned_word_length_limit());
+                    if let Some(wave) = &mut self.wave {
+                        wave.register_spawned_word();
+                    }
+                }
+                self.time_until_next_word = Some(self.next_word_loop_length);
+            } else {
+                self.time_until_next_word = Some(time_until_next_word - last_frame_length);
+            }
+        }
+
+        if let Some(wave) = &self.wave {
+            if wave.is_cleared(self.words.len()) {
+                self.start_intermission();
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, ctx: &mut Context) -> GameResult {
+        let mut canvas =
+            graphics::Canvas::from_frame(ctx, Color::from(self.get_theme().background));
+        if let Some(time_passed) = self.passed_time_since_game_end {
+            if time_passed < 4.0 {
+                self.draw_end_game_message(&mut canvas, ctx);
+                self.passed_time_since_game_end =
+                    Some(time_passed + ctx.time.delta().as_secs_f32());
+            } else {
+                self.passed_time_since_game_end = None
+            }
+        }
+        if !self.is_game_running {
+            self.draw_home_screen(&mut canvas, ctx);
+        } else {
+            self.draw_player_stats(&mut canvas);
+            self.draw_active_effects(&mut canvas);
+            match &self.wave {
+                Some(wave) if wave.is_in_intermission() => {
+                    self.draw_intermission(&mut canvas, ctx, wave)
+                }
+                _ => {
+                    self.draw_words(&mut canvas, ctx);
+                    if self.active_effects.is_active(WordEffect::Fog) {
+                        self.draw_fog(&mut canvas);
+                    }
+                    self.draw_effect_banner(&mut canvas, ctx);
+                }
+            }
+            self.draw_explosions(&mut canvas);
+            self.draw_damage_flash(&mut canvas);
+            self.draw_achievement_toast(&mut canvas, ctx);
+            if self.is_paused {
+                self.draw_pause_overlay(&mut canvas);
+                self.draw_home_screen(&mut canvas, ctx);
+            }
+        }
+        canvas.finish(ctx)
+    }
+
+    fn resize_event(&mut self, _ctx: &mut Context, width: f32, height: f32) -> GameResult {
+        self.resize(width, height);
+        Ok(())
+    }
+
+    fn focus_event(&mut self, _ctx: &mut Context, gained: bool) -> GameResult {
+        if !gained {
+            self.pause_game();
+        }
+        Ok(())
+    }
+
+    fn key_down_event(
+        &mut self,
+        _ctx: &mut Context,
+        input: ggez::input::keyboard::KeyInput,
+        _repeated: bool,
+    ) -> GameResult {
+        if let Some(input_key_code) = input.keycode {
+            self.handle_game_key(input_key_code)?
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ggez::conf::WindowMode;
+
+    use super::*;
+    use crate::achievements::HIGH_SCORE;
+    use crate::campaign::Level;
+    use crate::fonts::FALLBACK_FONT;
+    use crate::preset::Preset;
+
+    fn get_effect_duration(game: &Game, effect: WordEffect) -> f32 {
+        game.effect_table.get(effect).unwrap().duration
+    }
+
+    fn create_game() -> Game {
+        let conf = Conf::new().window_mode(WindowMode {
+            width: 1200.0,
+            height: 1000.0,
+            ..Default::default()
+        });
+        Game::new(&conf)
+    }
+
+    #[test]
+    fn it_initializes_game_with_defautl_state() {
+        let game = create_game();
+
+        assert_eq!(game.screen_height, 1000.0);
+        assert_eq!(game.screen_width, 1200.0);
+        assert_eq!(game.theme, Theme::default());
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+        assert_eq!(
+            game.menus.get_page(MAIN_PAGE),
+            &MenuPage::new(get_main_menu_items())
+        );
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE),
+            &MenuPage::new(get_settings_menu_items())
+        );
+        assert_eq!(
+            game.menus.get_page(FONTS_PAGE),
+            &MenuPage::new(get_fonts_menu_items(&game.fonts, &game.profile))
+        );
+        let themes_menu = &game.menus.get_page(THEMES_PAGE).menu;
+        assert_eq!(themes_menu.options.len(), get_bundled_themes().len());
+        assert_eq!(
+            themes_menu.options[themes_menu.selected_option_index],
+            "PURPLE (DARK)"
+        );
+        assert_eq!(
+            game.menus.get_page(MODES_PAGE),
+            &MenuPage::new(get_modes_menu_items(&game.profile))
+        );
+        assert_eq!(
+            game.menus.get_page(DIFFICULTY_PAGE),
+            &MenuPage::new(get_difficulty_menu_items(&game.profile))
+        );
+        assert_eq!(game.preset_settings, PresetSettings::default());
+        assert!(game.last_summary.is_none());
+        assert_eq!(game.game_mode, GameMode::Endless);
+        assert!(game.wave.is_none());
+        assert!(!game.is_game_running);
+        assert!(!game.is_paused);
+        assert_eq!(
+            game.menus.get_page(PAUSE_PAGE),
+            &MenuPage::new(get_pause_menu_items()).with_back_command(MenuCommand::Resume)
+        );
+        assert_eq!(game.words.len(), 0);
+        assert_eq!(game.next_word_loop_length, INITIAL_TIME_UNTIL_NEXT_WORD);
+        assert_eq!(game.current_score, 0);
+        assert_eq!(game.life_points, 0);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED);
+        assert!(game.time_until_next_word.is_none());
+        assert!(game.passed_time_since_game_end.is_none());
+        assert_eq!(game.active_effects, ActiveEffects::default());
+        assert!(game.spectator.is_none());
+    }
+
+    #[test]
+    fn it_starts_the_game_correctly() {
+        let mut game = create_game();
+
+        let start_res = game.start_game();
+        assert!(start_res.is_ok());
+
+        assert!(game.is_game_running);
+        assert_eq!(game.current_score, 0);
+        assert_eq!(game.life_points, game.rules.starting_lives);
+        assert_eq!(
+            game.time_until_next_word,
+            Some(INITIAL_TIME_UNTIL_NEXT_WORD)
+        );
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED);
+        assert_eq!(game.passed_time_since_game_end, None);
+    }
+
+    #[test]
     fn it_ends_the_game_correctly() {
         let mut game = create_game();
 
-        game.is_game_running = true;
+        game.is_game_running = true;
+
+        let word = Word::new("word", Point2 { x: 0.0, y: 0.0 }, 0);
+        game.words = VecDeque::from([word]);
+
+        let end_res = game.end_game();
+
+        assert!(end_res.is_ok());
+
+        assert!(!game.is_game_running);
+        assert_eq!(game.words.len(), 0);
+        assert_eq!(game.passed_time_since_game_end, Some(0.0))
+    }
+
+    #[test]
+    fn it_starts_the_game_when_play_is_selected_from_main_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(game.is_game_running)
+    }
+
+    #[test]
+    fn it_opens_the_settings_menu_when_settings_is_selected_from_main_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.menus.get_current_id(), SETTINGS_PAGE);
+    }
+
+    #[test]
+    fn it_opens_the_fonts_menu_when_fonts_is_selected_from_settings_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.menus.get_current_id(), FONTS_PAGE);
+    }
+
+    #[test]
+    fn it_sets_the_selected_font_from_fonts_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.words_font, String::from("GravitasOne"));
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.words_font, String::from("Creepster"));
+    }
+
+    #[test]
+    fn it_opens_the_themes_menu_when_change_theme_is_selected_from_settings_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.menus.get_current_id(), THEMES_PAGE);
+    }
+
+    #[test]
+    fn it_sets_the_selected_theme_from_themes_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.theme.name, "purple");
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Up);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.theme.name, "paper");
+        assert!(game.theme.is_light);
+    }
+
+    #[test]
+    fn it_previews_the_highlighted_theme_until_the_menu_is_left() {
+        let mut game = create_game();
+        game.menus.open(THEMES_PAGE);
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Up)
+            .unwrap();
+        assert_eq!(game.get_theme().name, "paper");
+        assert_eq!(game.theme.name, "purple");
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert_eq!(game.get_theme().name, "purple");
+        assert_eq!(
+            game.menus.get_page(THEMES_PAGE).menu.options
+                [game.menus.get_page(THEMES_PAGE).menu.selected_option_index],
+            "PURPLE (DARK)"
+        );
+    }
+
+    #[test]
+    fn it_ignores_locked_themes() {
+        let mut game = create_game();
+        game.menus.open(THEMES_PAGE);
+        game.menus
+            .get_page_mut(THEMES_PAGE)
+            .menu
+            .selected_option_index = 0;
+
+        assert_eq!(
+            game.menus.get_page(THEMES_PAGE).menu.options[0],
+            "CRIMSON (DARK) - LV 3"
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.theme.name, "purple");
+
+        game.set_profile(Profile {
+            xp: 1000,
+            ..Profile::default()
+        });
+        assert_eq!(
+            game.menus.get_page(THEMES_PAGE).menu.options[0],
+            "CRIMSON (DARK)"
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.theme.name, "crimson");
+    }
+
+    #[test]
+    fn it_toggles_the_spectator_label_in_settings_menu() {
+        let mut game = create_game();
+
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[2],
+            "SPECTATOR: OFF"
+        );
+
+        let res = game.enable_spectator(0);
+        assert!(res.is_ok());
+        assert!(game.spectator.is_some());
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[2],
+            "SPECTATOR: ON"
+        );
+
+        game.disable_spectator();
+        assert!(game.spectator.is_none());
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[2],
+            "SPECTATOR: OFF"
+        );
+    }
+
+    #[test]
+    fn it_selects_the_game_mode_from_modes_menu() {
+        let mut game = create_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.menus.get_current_id(), MODES_PAGE);
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.game_mode, GameMode::Waves);
+        assert_eq!(
+            game.menus.get_page(MAIN_PAGE).menu.options[2],
+            "MODE: WAVES"
+        );
+    }
+
+    #[test]
+    fn it_starts_a_wave_game_correctly() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Waves;
+
+        let start_res = game.start_game();
+        assert!(start_res.is_ok());
+
+        assert!(game.is_game_running);
+        assert_eq!(game.wave, Some(WaveState::new()));
+
+        let end_res = game.end_game();
+        assert!(end_res.is_ok());
+        assert!(game.wave.is_none());
+    }
+
+    #[test]
+    fn it_starts_the_next_wave_faster_than_the_base_speed() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Waves;
+        let start_res = game.start_game();
+        assert!(start_res.is_ok());
+
+        game.start_intermission();
+        assert!(game.wave.as_ref().unwrap().is_in_intermission());
+        assert!(!game.can_spawn_word());
+
+        game.start_next_wave();
+        assert_eq!(game.wave.as_ref().unwrap().wave_number, 2);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + WAVE_SPEED_INCREASE);
+        assert!(game.can_spawn_word());
+    }
+
+    #[test]
+    fn it_buys_upgrades_with_score_in_the_shop() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Waves;
+        let start_res = game.start_game();
+        assert!(start_res.is_ok());
+        game.start_intermission();
+
+        game.life_points = 0;
+        game.current_score = Upgrade::ExtraLife.cost() - 1;
+        let res = game.handle_game_key(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert_eq!(game.life_points, 0);
+
+        game.current_score = Upgrade::ExtraLife.cost();
+        let res = game.handle_game_key(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert_eq!(game.life_points, 1);
+        assert_eq!(game.current_score, 0);
+
+        game.current_score = 1000;
+        game.buy_upgrade(Upgrade::SlowerBaseSpeed);
+        assert_eq!(game.base_game_speed, INITIAL_GAME_SPEED - SLOWER_BASE_SPEED_UPGRADE);
+        game.buy_upgrade(Upgrade::LongerPowerUps);
+        assert_eq!(
+            game.active_effects.get_duration(WordEffect::SlowDown),
+            get_effect_duration(&game, WordEffect::SlowDown) + LONGER_POWER_UPS_UPGRADE
+        );
+        game.buy_upgrade(Upgrade::HigherEffectChance);
+        assert_eq!(
+            game.effect_spawn_chance,
+            DEFAULT_EFFECT_CHANCE + HIGHER_EFFECT_CHANCE_UPGRADE
+        );
+    }
+
+    #[test]
+    fn it_starts_the_next_wave_from_the_shop_menu() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Waves;
+        let start_res = game.start_game();
+        assert!(start_res.is_ok());
+        game.start_intermission();
+        assert_eq!(game.menus.get_current_id(), SHOP_PAGE);
+
+        for _ in 0..Upgrade::ALL.len() {
+            let res = game.handle_game_key(keyboard::KeyCode::Down);
+            assert!(res.is_ok());
+        }
+        // pausing keeps the shop and its selection
+        game.pause_game();
+        game.resume_game();
+        assert_eq!(game.menus.get_current_id(), SHOP_PAGE);
+        let res = game.handle_game_key(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(!game.wave.as_ref().unwrap().is_in_intermission());
+        assert_eq!(game.wave.as_ref().unwrap().wave_number, 2);
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+    }
+
+    fn create_campaign() -> Campaign {
+        let level = Level::from_toml_str(
+            r#"
+            name = "Level"
+            words = ["cat", "dog"]
+            allowed_effects = []
+            stars = [20, 30, 40]
+
+            [speed]
+            initial = 40
+            increase_per_word = 2
+
+            [spawn]
+            initial_interval = 1.5
+            decay = 0.01
+            min_interval = 0.5
+
+            [win]
+            complete_words = 2
+            "#,
+        )
+        .unwrap();
+        Campaign::new(Vec::from([level.clone(), level]))
+    }
+
+    #[test]
+    fn it_opens_the_levels_menu_when_playing_in_campaign_mode() {
+        let mut game = create_game();
+        game.set_campaign(create_campaign());
+        game.game_mode = GameMode::Campaign;
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(!game.is_game_running);
+        assert_eq!(game.menus.get_current_id(), LEVELS_PAGE);
+        assert_eq!(
+            game.menus.get_page(LEVELS_PAGE).menu.options,
+            ["1. LEVEL (0/3)", "2. LOCKED"]
+        );
+    }
+
+    #[test]
+    fn it_does_not_start_a_locked_level() {
+        let mut game = create_game();
+        game.set_campaign(create_campaign());
+
+        let res = game.start_level(1);
+        assert!(res.is_ok());
+
+        assert!(!game.is_game_running);
+    }
+
+    #[test]
+    fn it_starts_a_level_with_its_rules() {
+        let mut game = create_game();
+        game.set_campaign(create_campaign());
+        game.menus.open(LEVELS_PAGE);
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(game.is_game_running);
+        assert_eq!(game.current_level_index, Some(0));
+        assert_eq!(game.game_speed, 40);
+        assert_eq!(game.next_word_loop_length, 1.5);
+        assert_eq!(game.source_words, ["cat", "dog"]);
+        assert!(game.allowed_effects.is_empty());
+
+        game.spawn_new_word(None);
+        let word = game.words.back().unwrap();
+        assert!(word.value == "cat" || word.value == "dog");
+        assert!(word.effect.is_none());
+    }
+
+    #[test]
+    fn it_completes_a_level_and_unlocks_the_next_one() {
+        let mut game = create_game();
+        game.set_campaign(create_campaign());
+        let res = game.start_level(0);
+        assert!(res.is_ok());
+
+        game.words = VecDeque::from([
+            Word::new_with_effect("cat", Point2 { x: 1.0, y: 1.0 }, 0, None),
+            Word::new_with_effect("dog", Point2 { x: 1.0, y: 1.0 }, 0, None),
+        ]);
+        game.complete_word();
+        let res = game.check_level_completion();
+        assert!(res.is_ok());
+        assert!(game.is_game_running);
+
+        game.complete_word();
+        let res = game.check_level_completion();
+        assert!(res.is_ok());
+
+        assert!(!game.is_game_running);
+        assert_eq!(game.end_game_message, "LEVEL COMPLETE - 1/3 STARS");
+        assert!(game.campaign.as_ref().unwrap().is_unlocked(1));
+        assert_eq!(
+            game.menus.get_page(LEVELS_PAGE).menu.options,
+            ["1. LEVEL (1/3)", "2. LEVEL (0/3)"]
+        );
+    }
+
+    #[test]
+    fn it_selects_the_difficulty_and_starts_the_game_with_its_preset() {
+        let mut game = create_game();
+
+        game.menus.open(DIFFICULTY_PAGE);
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.preset_settings.difficulty, Difficulty::Easy);
+        assert_eq!(
+            game.menus.get_page(MAIN_PAGE).menu.options[3],
+            "DIFFICULTY: EASY"
+        );
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Left);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        let easy = Preset::get_builtin(Difficulty::Easy);
+        assert!(game.is_game_running);
+        assert_eq!(game.life_points, easy.starting_lives);
+        assert_eq!(game.game_speed, easy.starting_speed);
+        assert_eq!(game.rules.word_score, easy.word_score);
+    }
+
+    #[test]
+    fn it_edits_the_custom_preset() {
+        let mut game = create_game();
+
+        game.menus.open(DIFFICULTY_PAGE);
+        for _ in 0..Difficulty::ALL.len() {
+            let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+            assert!(res.is_ok());
+        }
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), CUSTOM_PRESET_PAGE);
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert_eq!(game.preset_settings.custom_preset.starting_lives, 4);
+        assert_eq!(
+            game.menus.get_page(CUSTOM_PRESET_PAGE).menu.options[0],
+            "LIVES: 4"
+        );
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Left);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), DIFFICULTY_PAGE);
+    }
+
+    #[test]
+    fn it_shows_the_results_compared_within_the_same_preset() {
+        let mut game = create_game();
+
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.current_score = 100;
+        let res = game.end_game();
+        assert!(res.is_ok());
+
+        assert_eq!(game.menus.get_current_id(), RESULTS_PAGE);
+        assert_eq!(game.last_summary.as_ref().unwrap().score, 100);
+        assert_eq!(game.get_results_lines().last().unwrap(), "NEW BEST!");
+
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.current_score = 50;
+        let res = game.end_game();
+        assert!(res.is_ok());
+
+        assert_eq!(game.get_results_lines().last().unwrap(), "BEST: 100");
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+    }
+
+    #[test]
+    fn it_pauses_and_resumes_the_game() {
+        let mut game = create_game();
+
+        game.pause_game();
+        assert!(!game.is_paused);
+
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.pause_game();
+
+        assert!(game.is_paused);
+        assert_eq!(game.menus.get_current_id(), PAUSE_PAGE);
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(!game.is_paused);
+        assert!(game.is_game_running);
+    }
+
+    #[test]
+    fn it_resumes_the_game_on_escape_in_pause_menu() {
+        let mut game = create_game();
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.pause_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Escape);
+        assert!(res.is_ok());
+
+        assert!(!game.is_paused);
+    }
+
+    #[test]
+    fn it_restarts_the_game_from_pause_menu() {
+        let mut game = create_game();
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.current_score = 50;
+        game.active_effects.apply(WordEffect::SlowDown);
+        game.words = VecDeque::from([Word::new("word", Point2 { x: 0.0, y: 0.0 }, 0)]);
+        game.pause_game();
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Down);
+        assert!(res.is_ok());
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(game.is_game_running);
+        assert!(!game.is_paused);
+        assert_eq!(game.current_score, 0);
+        assert_eq!(game.words.len(), 0);
+        assert!(!game.active_effects.is_active(WordEffect::SlowDown));
+    }
+
+    #[test]
+    fn it_opens_settings_from_pause_menu_and_goes_back_to_it() {
+        let mut game = create_game();
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.pause_game();
+
+        game.menus
+            .get_page_mut(PAUSE_PAGE)
+            .menu
+            .selected_option_index = 2;
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), SETTINGS_PAGE);
+
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Left);
+        assert!(res.is_ok());
+        assert_eq!(game.menus.get_current_id(), PAUSE_PAGE);
+        assert!(game.is_paused);
+    }
+
+    #[test]
+    fn it_quits_to_menu_from_pause_menu() {
+        let mut game = create_game();
+        let res = game.start_game();
+        assert!(res.is_ok());
+        game.words = VecDeque::from([Word::new("word", Point2 { x: 0.0, y: 0.0 }, 0)]);
+        game.pause_game();
+
+        game.menus
+            .get_page_mut(PAUSE_PAGE)
+            .menu
+            .selected_option_index = 3;
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+
+        assert!(!game.is_game_running);
+        assert!(!game.is_paused);
+        assert_eq!(game.words.len(), 0);
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+        assert!(game.last_summary.is_none());
+    }
+
+    #[test]
+    fn it_updates_words_positions_correctly() {
+        let mut game = create_game();
+
+        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.update_words_positions(0.2, 0.1);
+
+        assert_eq!(game.words.front().unwrap().position.y, 1.2);
+        assert_eq!(game.words.back().unwrap().position.y, 2.2);
+    }
+
+    #[test]
+    fn it_keeps_typing_the_front_word_when_another_word_overtakes_it() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        let word_1 = Word::new_with_effect("word1", Point2 { x: 1.0, y: 1.0 }, 1, None);
+        let word_2 = Word::new_with_effect("word2", Point2 { x: 0.3, y: 2.0 }, 0, None);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.update_words_positions(0.2, 0.1);
+        game.handle_typed_letter('o').unwrap();
+
+        assert_eq!(game.words.front().unwrap().value, "word1");
+        assert_eq!(game.words.front().unwrap().progress_index, 2);
+        assert_eq!(game.words.back().unwrap().progress_index, 0);
+    }
+
+    #[test]
+    fn it_loses_a_life_for_every_word_that_reaches_the_bottom() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.life_points = 3;
+        game.words = VecDeque::from([
+            Word::new_with_effect("word1", Point2 { x: 1.0, y: 900.0 }, 0, None),
+            Word::new_with_effect("word2", Point2 { x: 1.0, y: 990.0 }, 0, None),
+            Word::new_with_effect("word3", Point2 { x: 1.0, y: 995.0 }, 0, None),
+        ]);
+
+        game.update_words_positions(20.0, 0.1);
+        game.handle_fallen_words().unwrap();
+
+        assert_eq!(game.life_points, 1);
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.words.front().unwrap().value, "word1");
+    }
+
+    #[test]
+    fn it_spawns_a_new_word_correctly() {
+        let mut game = create_game();
+
+        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.spawn_new_word(None);
+        assert_eq!(game.words.len(), 3);
+        assert!(game.words.back().unwrap().position.x < 1000.0)
+    }
+
+    #[test]
+    fn it_spawns_a_new_word_with_len_limit_correctly() {
+        let mut game = create_game();
+
+        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.spawn_new_word(Some(3));
+        assert_eq!(game.words.len(), 3);
+        assert!(game.words.back().unwrap().position.x < 1000.0);
+        assert!(game.words.back().unwrap().value.graphemes(true).count() <= 3);
+    }
+
+    #[test]
+    fn it_completes_a_word_without_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        game.game_speed = INITIAL_GAME_SPEED;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = None;
+        let mut word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        word_2.effect = None;
+
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.current_score, 25);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+    }
+
+    #[test]
+    fn it_completes_a_word_with_add_life_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        game.game_speed = INITIAL_GAME_SPEED;
+        game.life_points = 0;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::AddLife);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.current_score, 25);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+        assert_eq!(game.life_points, 1);
+    }
+
+    #[test]
+    fn it_caps_the_lives_at_the_maximum() {
+        let mut game = create_game();
+        game.rules.max_lives = 2;
+        game.life_points = 2;
+
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::AddLife);
+        game.words = VecDeque::from([word_1]);
+        game.complete_word();
+
+        assert_eq!(game.life_points, 2);
+    }
+
+    #[test]
+    fn it_shows_damage_feedback_when_a_word_reaches_the_bottom() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.life_points = 2;
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word1",
+            Point2 { x: 1.0, y: 1.0 },
+            0,
+            None,
+        )]);
+
+        game.handle_missed_word(0).unwrap();
+
+        assert_eq!(game.life_points, 1);
+        assert!(game.words.is_empty());
+        assert!(game.damage_feedback.get_flash_alpha() > 0.0);
+        assert_eq!(game.damage_feedback.explosions.len(), 1);
+        assert_eq!(game.damage_feedback.explosions[0].value, "word1");
+        assert_eq!(
+            game.damage_feedback.breaking_life,
+            Some((1, LIFE_BREAK_LENGTH))
+        );
+    }
+
+    #[test]
+    fn it_completes_a_word_with_slow_down_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        game.game_speed = INITIAL_GAME_SPEED;
+        game.life_points = 0;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::SlowDown);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.current_score, 25);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+        assert_eq!(game.get_current_game_speed(), SLOW_DOWN_GAME_SPEED);
+        assert_eq!(
+            game.active_effects
+                .get(WordEffect::SlowDown)
+                .map(|active| active.time_left),
+            Some(get_effect_duration(&game, WordEffect::SlowDown))
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_game_speed_when_slow_downs_overlap() {
+        let mut game = create_game();
+        game.game_speed = INITIAL_GAME_SPEED;
+
+        game.apply_word_effect(WordEffect::SlowDown);
+        game.active_effects.tick(3.0);
+        game.apply_word_effect(WordEffect::SlowDown);
+        assert_eq!(game.get_current_game_speed(), SLOW_DOWN_GAME_SPEED);
+
+        game.active_effects.tick(3.0);
+        assert_eq!(game.get_current_game_speed(), SLOW_DOWN_GAME_SPEED);
+
+        game.active_effects
+            .tick(get_effect_duration(&game, WordEffect::SlowDown));
+        assert_eq!(game.get_current_game_speed(), INITIAL_GAME_SPEED);
+    }
+
+    #[test]
+    fn it_completes_a_word_with_short_words_spawn_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        game.game_speed = INITIAL_GAME_SPEED;
+        game.life_points = 0;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::SpawnOnlyShortWords);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.current_score, 25);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+        assert_eq!(
+            game.get_spawned_word_length_limit(),
+            Some(SHORT_WORD_LENGTH_LIMIT as usize)
+        );
+    }
+
+    #[test]
+    fn it_completes_a_word_with_freeze_effect_correctly() {
+        let mut game = create_game();
+
+        game.game_speed = INITIAL_GAME_SPEED;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::Freeze);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 1);
+        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+        assert_eq!(game.get_current_game_speed(), 0);
+        game.active_effects
+            .tick(get_effect_duration(&game, WordEffect::Freeze));
+        assert_eq!(game.get_current_game_speed(), INITIAL_GAME_SPEED + 5);
+    }
+
+    #[test]
+    fn it_completes_a_word_with_bomb_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::Bomb);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        let word_3 = Word::new("word3", Point2 { x: 0.6, y: 3.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2, word_3]);
+
+        game.complete_word();
+
+        assert_eq!(game.words.len(), 0);
+        assert_eq!(game.current_score, 39);
+        assert!(!game.active_effects.is_active(WordEffect::Bomb));
+    }
+
+    #[test]
+    fn it_completes_a_word_with_double_score_effect_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::DoubleScore);
+        let mut word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        word_2.effect = None;
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+        assert_eq!(game.current_score, 25);
+
+        game.complete_word();
+        assert_eq!(game.current_score, 55);
+    }
+
+    #[test]
+    fn it_completes_a_word_with_shield_effect_correctly() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+
+        game.life_points = 1;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::Shield);
+        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        let word_3 = Word::new("word3", Point2 { x: 0.6, y: 3.0 }, 0);
+        game.words = VecDeque::from([word_1, word_2, word_3]);
+
+        game.complete_word();
+        assert!(game.active_effects.is_active(WordEffect::Shield));
+
+        // the shield absorbs the first missed word and then breaks
+        game.handle_missed_word(0).unwrap();
+        assert_eq!(game.life_points, 1);
+        assert!(!game.active_effects.is_active(WordEffect::Shield));
+
+        game.handle_missed_word(0).unwrap();
+        assert_eq!(game.life_points, 0);
+        assert_eq!(game.words.len(), 0);
+    }
+
+    #[test]
+    fn it_completes_a_hazard_word_with_speed_burst_correctly() {
+        let mut game = create_game();
+
+        game.current_score = 10;
+        game.game_speed = INITIAL_GAME_SPEED;
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::SpeedBurst);
+        game.words = VecDeque::from([word_1]);
+
+        game.complete_word();
+
+        assert_eq!(game.current_score, 40);
+        assert_eq!(
+            game.get_current_game_speed(),
+            (INITIAL_GAME_SPEED + 5) * SPEED_BURST_PERCENT / 100
+        );
+    }
+
+    #[test]
+    fn it_completes_a_hazard_word_with_scramble_correctly() {
+        let mut game = create_game();
+
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::Scramble);
+        let mut word_2 = Word::new("climate", Point2 { x: 0.3, y: 2.0 }, 2);
+        word_2.effect = None;
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+
+        let scrambled = game.words.front().unwrap();
+        assert!(scrambled.value.starts_with("cl"));
+        let mut letters: Vec<char> = scrambled.value.chars().collect();
+        letters.sort();
+        assert_eq!(letters, ['a', 'c', 'e', 'i', 'l', 'm', 't']);
+    }
+
+    #[test]
+    fn it_completes_a_hazard_word_with_split_correctly() {
+        let mut game = create_game();
+
+        let mut word_1 = Word::new("climate", Point2 { x: 100.0, y: 50.0 }, 0);
+        word_1.effect = Some(WordEffect::Split);
+        game.words = VecDeque::from([word_1]);
+
+        game.complete_word();
+
+        let halves: Vec<&str> = game.words.iter().map(|word| word.value.as_str()).collect();
+        assert_eq!(halves, ["cli", "mate"]);
+        assert!(game.words.iter().all(|word| word.effect.is_none()));
+        assert!(game.words.iter().all(|word| word.position.y == 50.0));
+    }
+
+    #[test]
+    fn it_types_the_halves_of_a_split_word_straight_away() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+
+        let mut word_1 = Word::new_with_effect("climate", Point2 { x: 100.0, y: 50.0 }, 0, None);
+        word_1.effect = Some(WordEffect::Split);
+        let word_2 = Word::new_with_effect("dog", Point2 { x: 300.0, y: 10.0 }, 0, None);
+        game.words = VecDeque::from([word_1, word_2]);
+
+        game.complete_word();
+        let values: Vec<&str> = game.words.iter().map(|word| word.value.as_str()).collect();
+        assert_eq!(values, ["cli", "mate", "dog"]);
+
+        for letter in "climate".chars() {
+            game.handle_typed_letter(letter).unwrap();
+        }
+
+        let values: Vec<&str> = game.words.iter().map(|word| word.value.as_str()).collect();
+        assert_eq!(values, ["dog"]);
+        assert_eq!(game.words.front().unwrap().progress_index, 0);
+    }
+
+    #[test]
+    fn it_triggers_fog_and_mirror_only_when_the_hazard_word_is_missed() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.life_points = 2;
+
+        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_1.effect = Some(WordEffect::Mirror);
+        game.words = VecDeque::from([word_1]);
+        game.complete_word();
+        assert!(!game.active_effects.is_active(WordEffect::Mirror));
+
+        let mut word_2 = Word::new("word2", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_2.effect = Some(WordEffect::Fog);
+        let mut word_3 = Word::new("word3", Point2 { x: 1.0, y: 1.0 }, 0);
+        word_3.effect = Some(WordEffect::Mirror);
+        game.words = VecDeque::from([word_2, word_3]);
+        game.handle_missed_word(0).unwrap();
+        game.handle_missed_word(0).unwrap();
+
+        assert!(game.active_effects.is_active(WordEffect::Fog));
+        assert!(game.active_effects.is_active(WordEffect::Mirror));
+        assert_eq!(game.life_points, 0);
+    }
+
+    #[test]
+    fn it_spawns_hazards_only_with_the_hazard_chance() {
+        let mut game = create_game();
+        game.effect_spawn_chance = 0;
+        game.rules.hazard_chance = 100;
+        game.allowed_effects = vec![WordEffect::SlowDown, WordEffect::Fog];
+
+        game.spawn_new_word(None);
+        assert_eq!(game.words.back().unwrap().effect, Some(WordEffect::Fog));
+
+        game.rules.hazard_chance = 0;
+        game.spawn_new_word(None);
+        assert!(game.words.back().unwrap().effect.is_none());
+    }
+
+    #[test]
+    fn it_shows_a_banner_for_a_picked_up_effect_until_it_fades() {
+        let mut game = create_game();
+
+        game.apply_word_effect(WordEffect::Freeze);
+        assert_eq!(
+            game.effect_banner,
+            Some((WordEffect::Freeze, EFFECT_BANNER_DURATION))
+        );
+
+        game.update_effect_banner(EFFECT_BANNER_DURATION / 2.0);
+        assert!(game.effect_banner.is_some());
+        game.update_effect_banner(EFFECT_BANNER_DURATION / 2.0);
+        assert!(game.effect_banner.is_none());
+    }
+
+    #[test]
+    fn it_multiplies_the_score_once_the_streak_reaches_a_threshold() {
+        let mut game = create_game();
+        game.streak.current = 9;
+
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word1",
+            Point2 { x: 1.0, y: 1.0 },
+            0,
+            None,
+        )]);
+        game.complete_word();
+
+        assert_eq!(game.streak.current, 10);
+        assert_eq!(game.current_score, 30);
+    }
+
+    #[test]
+    fn it_breaks_the_streak_on_a_typing_mistake() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.words = VecDeque::from([
+            Word::new_with_effect("ab", Point2 { x: 1.0, y: 1.0 }, 0, None),
+            Word::new_with_effect("cd", Point2 { x: 1.0, y: 1.0 }, 0, None),
+        ]);
+
+        game.handle_typed_letter('a').unwrap();
+        game.handle_typed_letter('b').unwrap();
+        assert_eq!(game.streak.current, 1);
+
+        game.handle_typed_letter('x').unwrap();
+        assert_eq!(game.streak.current, 0);
+        assert_eq!(game.streak.broken, Some((1, STREAK_BREAK_ANIMATION_LENGTH)));
+        assert_eq!(game.streak.best, 1);
+    }
+
+    #[test]
+    fn it_breaks_the_streak_on_a_missed_word_and_keeps_the_best_in_the_results() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.life_points = 1;
+        game.streak.current = 4;
+        game.streak.best = 4;
+        game.words = VecDeque::from([Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0)]);
+
+        game.handle_missed_word(0).unwrap();
+        assert_eq!(game.streak.current, 0);
+
+        game.end_game().unwrap();
+        assert_eq!(game.last_summary.as_ref().unwrap().best_streak, 4);
+        assert!(game
+            .get_results_lines()
+            .contains(&String::from("BEST STREAK: 4")));
+    }
+
+    #[test]
+    fn it_reveals_new_words_only_briefly_in_blind_mode() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Blind;
+
+        game.spawn_new_word(None);
+        assert_eq!(
+            game.words.back().unwrap().visibility,
+            WordVisibility::Revealed {
+                time_left: BLIND_REVEAL_TIME
+            }
+        );
+
+        game.game_mode = GameMode::Endless;
+        game.spawn_new_word(None);
+        assert_eq!(
+            game.words.back().unwrap().visibility,
+            WordVisibility::Visible
+        );
+    }
+
+    #[test]
+    fn it_scores_blind_words_by_accuracy() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Blind;
+
+        let mut perfect_word = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
+        perfect_word.effect = None;
+        let mut sloppy_word = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
+        sloppy_word.effect = None;
+        sloppy_word.progress_index = 5;
+        sloppy_word.mistakes = 5;
+        game.words = VecDeque::from([perfect_word, sloppy_word]);
 
-        let word = Word::new("word", Point2 { x: 0.0, y: 0.0 }, 0);
-        game.words = VecDeque::from([word]);
+        game.complete_word();
+        assert_eq!(game.current_score, 30);
+        assert!(game.misremembered_words.is_empty());
 
-        let end_res = game.end_game();
+        game.complete_word();
+        assert_eq!(game.current_score, 38);
+        assert_eq!(game.misremembered_words, vec![String::from("word2")]);
+    }
 
-        assert!(end_res.is_ok());
+    #[test]
+    fn it_lists_misremembered_words_in_the_results() {
+        let mut game = create_game();
+        game.game_mode = GameMode::Blind;
+        game.start_game().unwrap();
+        game.misremembered_words = vec![String::from("climate"), String::from("ocean")];
 
-        assert_eq!(game.is_game_running, false);
-        assert_eq!(game.words.len(), 0);
-        assert_eq!(game.passed_time_since_game_end, Some(0.0))
+        game.end_game().unwrap();
+
+        let summary = game.last_summary.as_ref().unwrap();
+        assert_eq!(summary.misremembered_words.len(), 2);
+        let lines = game.get_results_lines();
+        assert!(lines.contains(&String::from("MISREMEMBERED:")));
+        assert!(lines.contains(&String::from("CLIMATE, OCEAN")));
     }
 
     #[test]
-    fn it_starts_the_game_when_play_is_selected_from_main_menu() {
+    fn it_grants_xp_at_the_end_of_a_game() {
         let mut game = create_game();
+        game.start_game().unwrap();
+        game.current_score = 1000;
+        game.end_game().unwrap();
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        assert_eq!(game.last_summary.as_ref().unwrap().xp, 100);
+        assert_eq!(game.profile.get_level(), 2);
+        assert_eq!(game.levels_gained, 1);
+        assert!(game
+            .get_results_lines()
+            .contains(&String::from("LEVEL UP! LV 2")));
+        assert_eq!(
+            game.menus.get_page(FONTS_PAGE).menu.options[2],
+            "BungeeShade"
+        );
+    }
 
-        assert!(game.is_game_running)
+    #[test]
+    fn it_does_not_select_locked_content() {
+        let mut game = create_game();
+        assert_eq!(
+            game.menus.get_page(MODES_PAGE).menu.options[3],
+            "BLIND - LV 4"
+        );
+
+        game.menus.open(MODES_PAGE);
+        game.menus
+            .get_page_mut(MODES_PAGE)
+            .menu
+            .selected_option_index = 3;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.game_mode, GameMode::Endless);
+        assert!(!game.is_game_running);
+
+        game.menus.open(FONTS_PAGE);
+        game.menus
+            .get_page_mut(FONTS_PAGE)
+            .menu
+            .selected_option_index = 2;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.words_font, "GravitasOne");
     }
 
     #[test]
-    fn it_opens_the_settings_menu_when_settings_is_selected_from_main_menu() {
+    fn it_lists_the_discovered_fonts_and_keeps_the_chosen_one() {
         let mut game = create_game();
+        game.words_font = String::from("Creepster");
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        game.set_fonts(vec![
+            String::from("Creepster"),
+            String::from("GravitasOne"),
+            String::from("Mono"),
+        ]);
 
-        assert_eq!(game.current_menu_type, MenuType::Settings);
+        assert_eq!(game.menus.get_page(FONTS_PAGE).menu.options[2], "Mono");
+        assert_eq!(
+            game.menus.get_page(FONTS_PAGE).menu.selected_option_index,
+            0
+        );
+        game.menus.open(FONTS_PAGE);
+        game.handle_input_key_in_menu(keyboard::KeyCode::Down)
+            .unwrap();
+        game.handle_input_key_in_menu(keyboard::KeyCode::Down)
+            .unwrap();
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.words_font, "Mono");
     }
 
     #[test]
-    fn it_opens_the_fonts_menu_when_fonts_is_selected_from_settings_menu() {
+    fn it_falls_back_to_the_default_font_when_the_saved_one_is_missing() {
         let mut game = create_game();
+        let settings = Settings {
+            words_font: String::from("Removed"),
+            ..Settings::default()
+        };
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        game.set_settings(&settings, None);
 
-        assert_eq!(game.current_menu_type, MenuType::Fonts);
+        assert_eq!(game.words_font, DEFAULT_WORDS_FONT);
+        assert_eq!(
+            game.menus.get_page(FONTS_PAGE).menu.selected_option_index,
+            0
+        );
     }
 
     #[test]
-    fn it_sets_the_selected_font_from_fonts_menu() {
+    fn it_uses_the_fallback_font_when_the_default_font_was_not_loaded() {
         let mut game = create_game();
+        game.set_fonts(vec![String::from("Creepster")]);
+        let settings = Settings {
+            words_font: String::from("Removed"),
+            ..Settings::default()
+        };
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        game.set_settings(&settings, None);
 
-        assert_eq!(game.words_font, String::from("GravitasOne"));
+        assert_eq!(game.words_font, FALLBACK_FONT);
+        assert_eq!(game.get_font(UI_FONT), FALLBACK_FONT);
+        assert_eq!(game.get_font(DANGER_FONT), DANGER_FONT);
+    }
 
-        let res = game.handle_input_key_in_fonts_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_fonts_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+    #[test]
+    fn it_cycles_the_ui_scale_and_word_size_in_settings_menu() {
+        let mut game = create_game();
+        game.menus.open(SETTINGS_PAGE);
 
-        assert_eq!(game.words_font, String::from("Creepster"));
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[4],
+            "UI SCALE: 100%"
+        );
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 4;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[4],
+            "UI SCALE: 125%"
+        );
+        assert_eq!(game.get_layout().ui(40.0), 50.0);
+
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[5],
+            "WORD SIZE: 40"
+        );
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 5;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[5],
+            "WORD SIZE: 48"
+        );
+        assert_eq!(game.get_settings().word_size, 48);
     }
 
     #[test]
-    fn it_opens_the_bg_colors_menu_when_bg_colors_is_selected_from_settings_menu() {
+    fn it_leaves_more_room_for_larger_words() {
         let mut game = create_game();
+        assert_eq!(game.get_max_word_x(), 1000.0);
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        game.word_size = 80;
+        assert_eq!(game.get_max_word_x(), 800.0);
+    }
+
+    #[test]
+    fn it_keeps_words_in_place_relative_to_the_resized_window() {
+        let mut game = create_game();
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word",
+            Point2 { x: 600.0, y: 500.0 },
+            0,
+            None,
+        )]);
 
-        assert_eq!(game.current_menu_type, MenuType::BgColors);
+        game.resize(600.0, 500.0);
+
+        assert_eq!(game.screen_width, 600.0);
+        assert_eq!(game.screen_height, 500.0);
+        assert_eq!(game.words[0].position, Point2 { x: 300.0, y: 250.0 });
+        assert_eq!(game.get_max_word_x(), 500.0);
     }
 
     #[test]
-    fn it_sets_the_selected_bg_color_from_bg_colors_menu() {
+    fn it_ignores_a_minimised_window() {
         let mut game = create_game();
 
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_main_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_settings_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+        game.resize(0.0, 0.0);
 
-        assert_eq!(game.bg_color, get_color_by_label(String::from("PURPLE")));
+        assert_eq!(game.screen_width, 1200.0);
+        assert_eq!(game.screen_height, 1000.0);
+    }
 
-        let res = game.handle_input_key_in_bg_colors_menu(keyboard::KeyCode::Down);
-        assert!(res.is_ok());
-        let res = game.handle_input_key_in_bg_colors_menu(keyboard::KeyCode::Space);
-        assert!(res.is_ok());
+    #[test]
+    fn it_makes_words_fall_the_same_share_of_the_screen_at_any_height() {
+        let mut game = create_game();
+        let reference = game.get_layout().get_fall_distance(100.0, 0.5);
 
-        assert_eq!(game.bg_color, get_color_by_label(String::from("GREEN")));
+        game.resize(1200.0, 2000.0);
+
+        assert_eq!(reference, 50.0);
+        assert_eq!(game.get_layout().get_fall_distance(100.0, 0.5), 100.0);
     }
 
     #[test]
-    fn it_updates_words_positions_correctly() {
+    fn it_cycles_the_display_mode_in_settings_menu() {
         let mut game = create_game();
+        game.menus.open(SETTINGS_PAGE);
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 6;
 
-        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[6],
+            "DISPLAY: WINDOWED"
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
 
-        game.update_words_positions(0.2);
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[6],
+            "DISPLAY: BORDERLESS"
+        );
+        assert!(game.is_display_mode_changed);
+        assert_eq!(game.get_settings().display_mode, DisplayMode::Borderless);
+    }
 
-        assert_eq!(game.words.front().unwrap().position.y, 1.2);
-        assert_eq!(game.words.back().unwrap().position.y, 2.2);
+    #[test]
+    fn it_does_not_save_the_command_line_overrides() {
+        let mut game = create_game();
+        game.set_display_mode(DisplayMode::Fullscreen);
+        assert!(game.set_difficulty(Difficulty::Hard));
+
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[6],
+            "DISPLAY: FULLSCREEN"
+        );
+        assert_eq!(game.get_settings().display_mode, DisplayMode::Windowed);
+        assert_eq!(game.get_saved_preset_settings(), PresetSettings::default());
+
+        game.menus.open(DIFFICULTY_PAGE);
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(
+            game.get_saved_preset_settings().difficulty,
+            Difficulty::Easy
+        );
     }
 
     #[test]
-    fn it_spawns_a_new_word_correctly() {
+    fn it_selects_with_enter_and_goes_back_with_escape() {
         let mut game = create_game();
 
-        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        game.menus
+            .get_page_mut(MAIN_PAGE)
+            .menu
+            .selected_option_index = 1;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Return)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), SETTINGS_PAGE);
 
-        game.spawn_new_word(None);
-        assert_eq!(game.words.len(), 3);
-        assert!(game.words.back().unwrap().position.x < 1000.0)
+        game.handle_input_key_in_menu(keyboard::KeyCode::Escape)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
     }
 
     #[test]
-    fn it_spawns_a_new_word_with_len_limit_correctly() {
+    fn it_rebinds_an_action_from_the_bindings_menu() {
         let mut game = create_game();
+        game.menus.open(SETTINGS_PAGE);
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 7;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), BINDINGS_PAGE);
 
-        let word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        game.menus
+            .get_page_mut(BINDINGS_PAGE)
+            .menu
+            .selected_option_index = 2;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(
+            game.menus.get_page(BINDINGS_PAGE).menu.options[2],
+            "SELECT: PRESS A KEY"
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Tab)
+            .unwrap();
 
-        game.spawn_new_word(Some(3));
-        assert_eq!(game.words.len(), 3);
-        assert!(game.words.back().unwrap().position.x < 1000.0);
-        assert!(game.words.back().unwrap().value.graphemes(true).count() <= 3);
+        assert_eq!(
+            game.menus.get_page(BINDINGS_PAGE).menu.options[2],
+            "SELECT: TAB"
+        );
+        assert_eq!(
+            game.get_settings().bindings.get_keys(Action::Select),
+            &[keyboard::KeyCode::Tab]
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert!(game.rebinding_action.is_none());
     }
 
     #[test]
-    fn it_completes_a_word_without_effect_correctly() {
+    fn it_reports_a_conflicting_binding_and_keeps_the_old_one() {
         let mut game = create_game();
+        game.menus.open(BINDINGS_PAGE);
+        game.menus
+            .get_page_mut(BINDINGS_PAGE)
+            .menu
+            .selected_option_index = 2;
 
-        game.current_score = 10;
-        game.game_speed = INITIAL_GAME_SPEED;
-        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        word_1.effect = None;
-        let mut word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        word_2.effect = None;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        game.handle_input_key_in_menu(keyboard::KeyCode::Up)
+            .unwrap();
 
-        game.words = VecDeque::from([word_1, word_2]);
+        assert_eq!(
+            game.bindings_message,
+            Some(String::from("UP IS ALREADY USED FOR UP"))
+        );
+        assert_eq!(
+            game.menus.get_page(BINDINGS_PAGE).menu.options[2],
+            "SELECT: SPACE, RETURN"
+        );
+        assert_eq!(game.bindings, Bindings::default());
+    }
 
-        game.complete_word();
+    #[test]
+    fn it_pauses_with_the_bound_pause_key() {
+        let mut game = create_game();
+        game.bindings
+            .bind(Action::Pause, keyboard::KeyCode::F1)
+            .unwrap();
+        game.start_game().unwrap();
 
-        assert_eq!(game.words.len(), 1);
-        assert_eq!(game.current_score, 20);
-        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
+        game.handle_game_key(keyboard::KeyCode::Escape).unwrap();
+        assert!(!game.is_paused);
+
+        game.handle_game_key(keyboard::KeyCode::F1).unwrap();
+        assert!(game.is_paused);
     }
 
     #[test]
-    fn it_completes_a_word_with_add_life_effect_correctly() {
+    fn it_applies_the_loaded_settings() {
         let mut game = create_game();
+        let settings = Settings {
+            words_font: String::from("Creepster"),
+            theme: String::from("green"),
+            hide_typed_letters: true,
+            ui_scale: 75,
+            display_mode: DisplayMode::Fullscreen,
+            ..Settings::default()
+        };
 
-        game.current_score = 10;
-        game.game_speed = INITIAL_GAME_SPEED;
-        game.life_points = 0;
-        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        word_1.effect = Some(WordEffect::AddLife);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        game.set_settings(&settings, None);
+
+        assert_eq!(game.words_font, "Creepster");
+        assert_eq!(game.theme.name, "green");
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[4],
+            "UI SCALE: 75%"
+        );
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[6],
+            "DISPLAY: FULLSCREEN"
+        );
+        assert!(!game.is_display_mode_changed);
+        assert_eq!(
+            game.menus.get_page(SETTINGS_PAGE).menu.options[3],
+            "HIDE TYPED: ON"
+        );
+        assert_eq!(game.get_settings(), settings);
+    }
+
+    #[test]
+    fn it_opens_the_achievements_screen_from_main_menu() {
+        let mut game = create_game();
+        game.menus
+            .get_page_mut(MAIN_PAGE)
+            .menu
+            .selected_option_index = 4;
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), ACHIEVEMENTS_PAGE);
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+    }
+
+    #[test]
+    fn it_shows_a_toast_when_an_achievement_is_unlocked() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.current_score = HIGH_SCORE;
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word1",
+            Point2 { x: 1.0, y: 1.0 },
+            0,
+            None,
+        )]);
 
         game.complete_word();
 
-        assert_eq!(game.words.len(), 1);
-        assert_eq!(game.current_score, 20);
-        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
-        assert_eq!(game.life_points, 1);
+        assert!(game.achievements.is_unlocked(Achievement::HighScore));
+        assert_eq!(
+            game.achievement_toasts.front(),
+            Some(&(Achievement::HighScore, ACHIEVEMENT_TOAST_DURATION))
+        );
+        game.update_achievement_toasts(ACHIEVEMENT_TOAST_DURATION);
+        assert!(game.achievement_toasts.is_empty());
     }
 
     #[test]
-    fn it_completes_a_word_with_slow_down_effect_correctly() {
+    fn it_shows_the_word_score_breakdown_in_the_results() {
         let mut game = create_game();
+        game.start_game().unwrap();
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word1",
+            Point2 { x: 1.0, y: 1.0 },
+            0,
+            None,
+        )]);
+        game.complete_word();
+        game.end_game().unwrap();
+        game.menus.open(RESULTS_PAGE);
 
-        game.current_score = 10;
-        game.game_speed = INITIAL_GAME_SPEED;
-        game.life_points = 0;
-        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        word_1.effect = Some(WordEffect::SlowDown);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        assert!(game
+            .get_results_lines()
+            .contains(&String::from("RIGHT: WORD SCORES")));
+        game.handle_input_key_in_menu(keyboard::KeyCode::Right)
+            .unwrap();
+        assert_eq!(
+            game.get_results_lines(),
+            vec![
+                String::from("WORD SCORES"),
+                String::from("WORD1 10+0+5+0 = 15")
+            ]
+        );
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), RESULTS_PAGE);
+        assert!(game.results_details_offset.is_none());
+    }
 
+    #[test]
+    fn it_names_the_bound_details_key_in_the_results() {
+        let mut game = create_game();
+        game.bindings
+            .bind(Action::Details, keyboard::KeyCode::Tab)
+            .unwrap();
+        game.start_game().unwrap();
+        game.words = VecDeque::from([Word::new_with_effect(
+            "word1",
+            Point2 { x: 1.0, y: 1.0 },
+            0,
+            None,
+        )]);
         game.complete_word();
+        game.end_game().unwrap();
 
-        assert_eq!(game.words.len(), 1);
-        assert_eq!(game.current_score, 20);
-        assert_eq!(game.game_speed, 25);
+        assert!(game
+            .get_results_lines()
+            .contains(&String::from("TAB: WORD SCORES")));
+    }
+
+    #[test]
+    fn it_toggles_hiding_typed_letters_from_the_settings_menu() {
+        let mut game = create_game();
+        game.menus.open(SETTINGS_PAGE);
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 3;
+
+        game.handle_input_key_in_menu(VirtualKeyCode::Space)
+            .unwrap();
+        assert!(game.hide_typed_letters);
         assert_eq!(
-            game.game_speed_before_slow_down,
-            Some(INITIAL_GAME_SPEED + 5)
+            game.menus.get_page(SETTINGS_PAGE).menu.options[3],
+            "HIDE TYPED: ON"
         );
-        assert_eq!(game.slow_down_time_left, Some(5.0));
     }
 
     #[test]
-    fn it_completes_a_word_with_short_words_spawn_effect_correctly() {
+    fn it_picks_the_motion_mix_from_the_game_mode() {
         let mut game = create_game();
 
-        game.current_score = 10;
-        game.game_speed = INITIAL_GAME_SPEED;
-        game.life_points = 0;
-        let mut word_1 = Word::new("word1", Point2 { x: 1.0, y: 1.0 }, 0);
-        word_1.effect = Some(WordEffect::SpawnOnlyShortWords);
-        let word_2 = Word::new("word2", Point2 { x: 0.3, y: 2.0 }, 0);
-        game.words = VecDeque::from([word_1, word_2]);
+        game.game_mode = GameMode::Waves;
+        game.reset_rules();
+        assert_eq!(game.motion_patterns, MotionPattern::ALL);
 
-        game.complete_word();
+        game.game_mode = GameMode::Endless;
+        game.reset_rules();
+        assert_eq!(game.motion_patterns, [MotionPattern::Linear]);
+    }
 
-        assert_eq!(game.words.len(), 1);
-        assert_eq!(game.current_score, 20);
-        assert_eq!(game.game_speed, INITIAL_GAME_SPEED + 5);
-        assert_eq!(game.spawn_only_short_words_time_left, Some(5.0));
+    #[test]
+    fn it_spawns_words_with_one_of_the_allowed_motion_patterns() {
+        let mut game = create_game();
+        game.motion_patterns = vec![MotionPattern::DiagonalBounce];
+
+        for _ in 0..20 {
+            game.spawn_new_word(None);
+        }
+
+        for word in &game.words {
+            assert_eq!(word.motion.pattern, MotionPattern::DiagonalBounce);
+            assert!(word.position.x >= 0.0 && word.position.x <= game.get_max_word_x());
+        }
+    }
+
+    #[test]
+    fn it_spawns_the_same_words_with_the_same_seed() {
+        let spawn_words = |seed: u64| {
+            let mut game = create_game();
+            game.set_seed(seed);
+            game.start_game().unwrap();
+            for _ in 0..10 {
+                game.spawn_new_word(None);
+            }
+            game.words
+                .iter()
+                .map(|word| (word.value.clone(), word.position.x, word.effect))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(spawn_words(7), spawn_words(7));
+        assert_ne!(spawn_words(7), spawn_words(8));
+    }
+
+    #[test]
+    fn it_keeps_custom_source_words_after_resetting_the_rules() {
+        let mut game = create_game();
+        game.set_source_words(vec![String::from("rust"), String::from("crab")]);
+
+        game.reset_rules();
+        game.spawn_new_word(None);
+
+        assert_eq!(game.source_words, vec!["rust", "crab"]);
+        assert!(["rust", "crab"].contains(&game.words[0].value.as_str()));
+    }
+
+    #[test]
+    fn it_refuses_to_pick_a_locked_mode() {
+        let mut game = create_game();
+
+        assert!(!game.set_game_mode(GameMode::Blind));
+        assert_eq!(game.game_mode, GameMode::Endless);
+
+        assert!(game.set_game_mode(GameMode::Waves));
+        assert_eq!(game.game_mode, GameMode::Waves);
+        assert_eq!(
+            game.menus.get_page(MAIN_PAGE).menu.options[2],
+            get_mode_option_label(GameMode::Waves)
+        );
+    }
+
+    #[test]
+    fn it_starts_a_game_when_skipping_the_menu() {
+        let mut game = create_game();
+        game.set_game_mode(GameMode::Waves);
+
+        game.skip_menu().unwrap();
+
+        assert!(game.is_game_running);
+        assert!(game.wave.is_some());
+    }
+
+    #[test]
+    fn it_opens_the_levels_menu_when_skipping_the_menu_without_levels() {
+        let mut game = create_game();
+        game.set_game_mode(GameMode::Campaign);
+
+        game.skip_menu().unwrap();
+
+        assert!(!game.is_game_running);
+        assert_eq!(game.menus.get_current_id(), LEVELS_PAGE);
+
+        // the only item only goes back
+        let res = game.handle_input_key_in_menu(keyboard::KeyCode::Space);
+        assert!(res.is_ok());
+        assert!(!game.is_game_running);
+        assert_eq!(game.menus.get_current_id(), MAIN_PAGE);
+    }
+
+    #[test]
+    fn it_goes_back_through_every_opened_page() {
+        let mut game = create_game();
+        game.start_game().unwrap();
+        game.pause_game();
+
+        game.menus
+            .get_page_mut(PAUSE_PAGE)
+            .menu
+            .selected_option_index = 2;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        game.menus
+            .get_page_mut(SETTINGS_PAGE)
+            .menu
+            .selected_option_index = 7;
+        game.handle_input_key_in_menu(keyboard::KeyCode::Space)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), BINDINGS_PAGE);
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), SETTINGS_PAGE);
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert_eq!(game.menus.get_current_id(), PAUSE_PAGE);
+        assert!(game.is_paused);
+
+        game.handle_input_key_in_menu(keyboard::KeyCode::Left)
+            .unwrap();
+        assert!(!game.is_paused);
+        assert!(game.is_game_running);
     }
 }
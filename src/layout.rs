@@ -0,0 +1,162 @@
+use ggez::conf::FullscreenType;
+use serde::{Deserialize, Serialize};
+
+// sizes and positions are written for the reference window and scaled to the real one
+pub const REFERENCE_WIDTH: f32 = 1200.0;
+pub const REFERENCE_HEIGHT: f32 = 1000.0;
+pub const DEFAULT_UI_SCALE: u32 = 100;
+pub const DEFAULT_WORD_SIZE: u32 = 40;
+pub const UI_SCALES: [u32; 6] = [50, 75, 100, 125, 150, 200];
+pub const WORD_SIZES: [u32; 6] = [24, 32, 40, 48, 64, 80];
+
+const MENU_TOP: f32 = 200.0;
+const MENU_TEXT_SIZE: f32 = 50.0;
+const MENU_SPACING: f32 = 70.0;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    // a window without decorations covering the whole screen
+    Borderless,
+    Fullscreen,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 3] = [
+        DisplayMode::Windowed,
+        DisplayMode::Borderless,
+        DisplayMode::Fullscreen,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            DisplayMode::Windowed => String::from("WINDOWED"),
+            DisplayMode::Borderless => String::from("BORDERLESS"),
+            DisplayMode::Fullscreen => String::from("FULLSCREEN"),
+        }
+    }
+
+    pub fn get_fullscreen_type(&self) -> FullscreenType {
+        match self {
+            DisplayMode::Windowed => FullscreenType::Windowed,
+            DisplayMode::Borderless => FullscreenType::Desktop,
+            DisplayMode::Fullscreen => FullscreenType::True,
+        }
+    }
+
+    pub fn next(&self) -> DisplayMode {
+        let index = DisplayMode::ALL
+            .iter()
+            .position(|mode| mode == self)
+            .unwrap_or(0);
+        DisplayMode::ALL[(index + 1) % DisplayMode::ALL.len()]
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Layout {
+    pub screen_width: f32,
+    pub screen_height: f32,
+    // percent of the window-relative size
+    pub ui_scale: u32,
+    // pixels at the reference window size
+    pub word_size: u32,
+}
+
+impl Layout {
+    pub fn new(screen_width: f32, screen_height: f32, ui_scale: u32, word_size: u32) -> Layout {
+        Layout {
+            screen_width,
+            screen_height,
+            ui_scale,
+            word_size,
+        }
+    }
+
+    // the tighter side decides so nothing ends up off screen
+    pub fn get_window_scale(&self) -> f32 {
+        (self.screen_width / REFERENCE_WIDTH).min(self.screen_height / REFERENCE_HEIGHT)
+    }
+
+    // a hud or menu size at the reference window in pixels
+    pub fn ui(&self, size: f32) -> f32 {
+        size * self.get_window_scale() * self.ui_scale as f32 / 100.0
+    }
+
+    // a size that grows with the falling words, like the room a word needs
+    pub fn word(&self, size: f32) -> f32 {
+        size * self.get_window_scale() * self.word_size as f32 / DEFAULT_WORD_SIZE as f32
+    }
+
+    // falling speeds are in pixels per second at the reference height
+    pub fn get_fall_distance(&self, speed: f32, last_frame_length: f32) -> f32 {
+        speed * last_frame_length * self.screen_height / REFERENCE_HEIGHT
+    }
+
+    pub fn get_word_size(&self) -> f32 {
+        self.word(DEFAULT_WORD_SIZE as f32)
+    }
+
+    pub fn from_bottom(&self, offset: f32) -> f32 {
+        self.screen_height - self.ui(offset)
+    }
+
+    pub fn from_right(&self, offset: f32) -> f32 {
+        self.screen_width - self.ui(offset)
+    }
+
+    // the left edge that centres something of the given width
+    pub fn center_x(&self, width: f32) -> f32 {
+        (self.screen_width - width) / 2.0
+    }
+
+    pub fn get_menu_text_size(&self) -> f32 {
+        self.ui(MENU_TEXT_SIZE)
+    }
+
+    // the index counts rows on screen, see get_menu_scroll
+    pub fn get_menu_option_y(&self, index: usize) -> f32 {
+        self.ui(MENU_TOP + MENU_SPACING * index as f32)
+    }
+
+    // how many menu options fit between the top of the menu and the bottom of the window
+    pub fn get_visible_menu_rows(&self) -> usize {
+        let space = self.screen_height - self.ui(MENU_TOP) - self.get_menu_text_size();
+        (space / self.ui(MENU_SPACING)).max(0.0) as usize + 1
+    }
+
+    // the first option drawn, a menu longer than the window scrolls to keep the selection visible
+    pub fn get_menu_scroll(&self, selected_index: usize) -> usize {
+        (selected_index + 1).saturating_sub(self.get_visible_menu_rows())
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout::new(
+            REFERENCE_WIDTH,
+            REFERENCE_HEIGHT,
+            DEFAULT_UI_SCALE,
+            DEFAULT_WORD_SIZE,
+        )
+    }
+}
+
+// hand-edited values snap to the closest one the settings cycle through
+pub fn get_nearest_option(options: &[u32], value: u32) -> u32 {
+    options
+        .iter()
+        .copied()
+        .min_by_key(|option| option.abs_diff(value))
+        .unwrap_or(value)
+}
+
+// the next value of a settings cycle, unknown values start over from the first one
+pub fn get_next_option(options: &[u32], current: u32) -> u32 {
+    match options.iter().position(|option| *option == current) {
+        Some(index) => options[(index + 1) % options.len()],
+        None => options[0],
+    }
+}
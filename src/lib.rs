@@ -1,4 +1,24 @@
+pub mod campaign;
 pub mod constants;
 pub mod game;
 pub mod word;
 pub mod menu;
+pub mod preset;
+pub mod results;
+pub mod spectator;
+pub mod wave;
+pub mod rules;
+pub mod storage;
+pub mod motion;
+pub mod effects;
+pub mod streak;
+pub mod scoring;
+pub mod lives;
+pub mod achievements;
+pub mod progression;
+pub mod settings;
+pub mod theme;
+pub mod fonts;
+pub mod layout;
+pub mod bindings;
+pub mod cli;
@@ -0,0 +1,67 @@
+use ggez::mint::Point2;
+
+pub const DAMAGE_FLASH_LENGTH: f32 = 0.4;
+pub const EXPLOSION_LENGTH: f32 = 0.8;
+pub const LIFE_BREAK_LENGTH: f32 = 0.8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WordExplosion {
+    pub value: String,
+    pub position: Point2<f32>,
+    pub time_left: f32,
+}
+
+impl WordExplosion {
+    // how far the explosion has gone, between 0 and 1
+    pub fn get_progress(&self) -> f32 {
+        (1.0 - self.time_left / EXPLOSION_LENGTH).clamp(0.0, 1.0)
+    }
+}
+
+// what is shown when a word reaches the bottom
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DamageFeedback {
+    pub flash_time_left: f32,
+    pub explosions: Vec<WordExplosion>,
+    // the index of the life icon that is breaking and how long it is still shown
+    pub breaking_life: Option<(u32, f32)>,
+}
+
+impl DamageFeedback {
+    pub fn explode_word(&mut self, value: &str, position: Point2<f32>) {
+        self.explosions.push(WordExplosion {
+            value: String::from(value),
+            position,
+            time_left: EXPLOSION_LENGTH,
+        });
+    }
+
+    pub fn lose_life(&mut self, life_index: u32) {
+        self.flash_time_left = DAMAGE_FLASH_LENGTH;
+        self.breaking_life = Some((life_index, LIFE_BREAK_LENGTH));
+    }
+
+    // the opacity of the red flash, fading out
+    pub fn get_flash_alpha(&self) -> f32 {
+        (self.flash_time_left / DAMAGE_FLASH_LENGTH).clamp(0.0, 1.0)
+    }
+
+    pub fn update(&mut self, last_frame_length: f32) {
+        self.flash_time_left = (self.flash_time_left - last_frame_length).max(0.0);
+        self.explosions.retain_mut(|explosion| {
+            explosion.time_left -= last_frame_length;
+            explosion.time_left > 0.0
+        });
+        if let Some((life_index, time_left)) = self.breaking_life {
+            self.breaking_life = if time_left - last_frame_length <= 0.0 {
+                None
+            } else {
+                Some((life_index, time_left - last_frame_length))
+            };
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
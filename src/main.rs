@@ -1,18 +1,58 @@
 use std::{env, path};
 
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
 use ggez::conf::{Conf, WindowMode};
 use ggez::event::{self};
-use ggez::{graphics, ContextBuilder};
+use ggez::ContextBuilder;
+use typing_tutor::achievements::Achievements;
+use typing_tutor::campaign::{self, Campaign};
+use typing_tutor::cli::{self, Cli, MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH};
+use typing_tutor::effects;
+use typing_tutor::fonts;
 use typing_tutor::game::Game;
+use typing_tutor::layout::{DisplayMode, REFERENCE_HEIGHT, REFERENCE_WIDTH};
+use typing_tutor::preset::PresetSettings;
+use typing_tutor::progression::{self, Profile};
+use typing_tutor::results::ResultsHistory;
+use typing_tutor::settings::{self, Settings};
+use typing_tutor::spectator::DEFAULT_SPECTATOR_PORT;
+use typing_tutor::storage::{self, GAME_AUTHOR, GAME_ID};
+use typing_tutor::theme;
 
 fn main() {
+    let cli = Cli::parse();
+    // read before the window opens so a bad list fails like any other invalid argument
+    let words = cli.words.as_deref().map(|path| {
+        cli::read_word_list(path)
+            .unwrap_or_else(|err| Cli::command().error(ErrorKind::InvalidValue, err).exit())
+    });
+
+    let settings_path = cli.config.clone().or_else(settings::get_settings_path);
+    let settings = settings_path
+        .as_deref()
+        .map(Settings::load)
+        .unwrap_or_default();
+    // the command line overrides are not saved with the settings
+    let display_mode = if cli.fullscreen {
+        DisplayMode::Fullscreen
+    } else {
+        settings.display_mode
+    };
+    let (width, height) = cli
+        .window_size
+        .unwrap_or((REFERENCE_WIDTH, REFERENCE_HEIGHT));
     let conf = Conf::new().window_mode(WindowMode {
-        width: 1200.0,
-        height: 1000.0,
+        width,
+        height,
+        resizable: true,
+        min_width: MIN_WINDOW_WIDTH,
+        min_height: MIN_WINDOW_HEIGHT,
+        fullscreen_type: display_mode.get_fullscreen_type(),
         ..Default::default()
     });
 
-    let (mut ctx, event_loop) = ContextBuilder::new("shooter", "FMI")
+    let (mut ctx, event_loop) = ContextBuilder::new(GAME_ID, GAME_AUTHOR)
         .default_conf(conf.clone())
         .build()
         .unwrap();
@@ -25,12 +65,81 @@ fn main() {
     }
 
     // load fonts
-    let font_data = graphics::FontData::from_path(&ctx, "/GravitasOne.ttf").unwrap();
-    ctx.gfx.add_font("GravitasOne", font_data);
-    let font_data = graphics::FontData::from_path(&ctx, "/BungeeShade.ttf").unwrap();
-    ctx.gfx.add_font("BungeeShade", font_data);
-    let font_data = graphics::FontData::from_path(&ctx, "/Creepster.ttf").unwrap();
-    ctx.gfx.add_font("Creepster", font_data);
-    let state = Game::new(&conf);
+    let user_fonts_dir =
+        storage::get_data_dir().map(|data_dir| fonts::get_user_fonts_dir(&data_dir));
+    let font_names = fonts::load_fonts(&mut ctx, user_fonts_dir.as_deref());
+    let mut state = Game::new(&conf);
+    let (width, height) = ctx.gfx.drawable_size();
+    state.resize(width, height);
+    state.set_fonts(font_names);
+    match theme::load_themes(&ctx) {
+        Ok(themes) => state.set_themes(themes),
+        Err(err) => eprintln!("could not load the themes: {}", err),
+    }
+    state.set_settings(&settings, settings_path);
+    state.set_display_mode(display_mode);
+    if let Some(data_dir) = storage::get_data_dir() {
+        let presets_path = data_dir.join("presets.toml");
+        state.set_preset_settings(PresetSettings::load(&presets_path), Some(presets_path));
+        let results = ResultsHistory::load(&data_dir.join("results.toml"));
+        state.set_achievements(Achievements::load(&data_dir.join("achievements.toml")));
+        let profile_path =
+            progression::get_profile_path(&data_dir, &progression::get_profile_name());
+        let mut profile = Profile::load(&profile_path);
+        profile.keep_used_content(&results);
+        state.set_results_history(results);
+        state.set_profile(profile);
+    }
+    match effects::load_effect_table(&ctx) {
+        Ok(effect_table) => state.set_effect_table(effect_table),
+        Err(err) => eprintln!("could not load the effect definitions: {}", err),
+    }
+    match campaign::load_levels(&ctx) {
+        Ok(levels) => {
+            let campaign = match storage::get_data_dir() {
+                Some(data_dir) => {
+                    Campaign::with_saved_progress(levels, data_dir.join("campaign.toml"))
+                }
+                None => Campaign::new(levels),
+            };
+            state.set_campaign(campaign);
+        }
+        Err(err) => eprintln!("could not load the campaign levels: {}", err),
+    }
+    if let Some(words) = words {
+        state.set_source_words(words);
+    }
+    if let Some(seed) = cli.seed {
+        state.set_seed(seed);
+    }
+    // locked content stays locked on the command line too
+    if let Some(mode) = cli.mode {
+        if !state.set_game_mode(mode) {
+            let err = format!(
+                "the {} mode is still locked",
+                mode.get_label().to_lowercase()
+            );
+            Cli::command().error(ErrorKind::InvalidValue, err).exit();
+        }
+    }
+    if let Some(difficulty) = cli.difficulty {
+        if !state.set_difficulty(difficulty) {
+            let err = format!(
+                "the {} difficulty is still locked",
+                difficulty.get_label().to_lowercase()
+            );
+            Cli::command().error(ErrorKind::InvalidValue, err).exit();
+        }
+    }
+    if settings.spectator || cli.spectator {
+        if let Err(err) = state.enable_spectator(DEFAULT_SPECTATOR_PORT) {
+            eprintln!("could not start the spectator server: {}", err);
+        }
+    }
+    if cli.skip_menu {
+        if let Err(err) = state.skip_menu() {
+            eprintln!("could not start the game: {}", err);
+        }
+    }
     event::run(ctx, event_loop, state);
 }
@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+
 use ggez::{graphics::{Canvas, self, Drawable, Color}, Context, mint::Point2};
 
+use crate::layout::Layout;
+use crate::theme::Theme;
+
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Menu {
@@ -13,21 +18,27 @@ pub enum MenuMove {
 }
 
 impl Menu {
-    pub fn draw(&self, canvas: &mut Canvas, ctx: &Context, screen_width: f32) {
-        for (idx, opt) in self.options.iter().enumerate() {
+    pub fn draw(&self, canvas: &mut Canvas, ctx: &Context, layout: &Layout, theme: &Theme, font: &str) {
+        self.draw_with_fonts(canvas, ctx, layout, theme, font, &[]);
+    }
+
+    // every option with a font of its own is drawn in it, the rest use the menu font
+    pub fn draw_with_fonts(&self, canvas: &mut Canvas, ctx: &Context, layout: &Layout, theme: &Theme, font: &str, fonts: &[String]) {
+        let scroll = layout.get_menu_scroll(self.selected_option_index);
+        for (idx, opt) in self.options.iter().enumerate().skip(scroll).take(layout.get_visible_menu_rows()) {
             let mut text = graphics::Text::new(opt);
-            text.set_font("BungeeShade");
+            text.set_font(fonts.get(idx).map_or(font, |font| font.as_str()));
 
-            text.set_scale(graphics::PxScale::from(50.0));
-            let text_width = text.dimensions(ctx).unwrap().w;
-            let color = if idx == self.selected_option_index { Color::YELLOW } else { Color::WHITE };
+            text.set_scale(graphics::PxScale::from(layout.get_menu_text_size()));
+            let text_width = text.dimensions(ctx).map_or(0.0, |rect| rect.w);
+            let color = if idx == self.selected_option_index { Color::from(theme.highlight) } else { Color::from(theme.text) };
             canvas.draw(
                 &text,
                 graphics::DrawParam::default()
                     .color(color)
                     .dest(Point2 {
-                        x: screen_width / 2.0 - text_width / 2.0,
-                        y: 200.0 + (70 * idx) as f32,
+                        x: layout.center_x(text_width),
+                        y: layout.get_menu_option_y(idx - scroll),
                     }),
             )
         }
@@ -55,4 +66,156 @@ impl Menu {
             self.selected_option_index += 1;
         }
     }
-}
\ No newline at end of file
+}
+
+// what choosing an item does, commands are left to the owner of the tree
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MenuTarget<C> {
+    Command(C),
+    Submenu(&'static str),
+    Back,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MenuItem<C> {
+    pub label: String,
+    pub target: MenuTarget<C>,
+}
+
+impl<C> MenuItem<C> {
+    pub fn command(label: String, command: C) -> Self {
+        Self { label, target: MenuTarget::Command(command) }
+    }
+
+    pub fn submenu(label: String, page_id: &'static str) -> Self {
+        Self { label, target: MenuTarget::Submenu(page_id) }
+    }
+
+    pub fn back(label: String) -> Self {
+        Self { label, target: MenuTarget::Back }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MenuPage<C> {
+    pub menu: Menu,
+    targets: Vec<MenuTarget<C>>,
+    // runs when the page is left with back, also when there is no page to go back to
+    back_command: Option<C>,
+}
+
+impl<C: Clone + PartialEq> MenuPage<C> {
+    pub fn new(items: Vec<MenuItem<C>>) -> Self {
+        let mut page = Self {
+            menu: Menu { options: Vec::new(), selected_option_index: 0 },
+            targets: Vec::new(),
+            back_command: None,
+        };
+        page.set_items(items);
+        page
+    }
+
+    pub fn with_back_command(mut self, command: C) -> Self {
+        self.back_command = Some(command);
+        self
+    }
+
+    // keeps the selection where it was as long as the new list is long enough
+    pub fn set_items(&mut self, items: Vec<MenuItem<C>>) {
+        let (options, targets) = items.into_iter().map(|item| (item.label, item.target)).unzip();
+        self.menu.options = options;
+        self.targets = targets;
+        self.menu.selected_option_index = self.menu.selected_option_index.min(self.targets.len().saturating_sub(1));
+    }
+
+    pub fn get_selected_target(&self) -> Option<&MenuTarget<C>> {
+        self.targets.get(self.menu.selected_option_index)
+    }
+}
+
+// pages are looked up by id, the open ones form a back-stack with the current page on top
+pub struct MenuTree<C> {
+    pages: HashMap<&'static str, MenuPage<C>>,
+    stack: Vec<&'static str>,
+}
+
+impl<C: Clone + PartialEq> MenuTree<C> {
+    pub fn new(root_id: &'static str, root: MenuPage<C>) -> Self {
+        Self { pages: HashMap::from([(root_id, root)]), stack: vec![root_id] }
+    }
+
+    pub fn add_page(&mut self, id: &'static str, page: MenuPage<C>) {
+        self.pages.insert(id, page);
+    }
+
+    // panics on ids without a page, every page is added when the tree is built
+    pub fn get_page(&self, id: &str) -> &MenuPage<C> {
+        self.pages.get(id).unwrap_or_else(|| panic!("no menu page {}", id))
+    }
+
+    pub fn get_page_mut(&mut self, id: &str) -> &mut MenuPage<C> {
+        self.pages.get_mut(id).unwrap_or_else(|| panic!("no menu page {}", id))
+    }
+
+    pub fn get_current_id(&self) -> &'static str {
+        self.stack[self.stack.len() - 1]
+    }
+
+    pub fn get_current_page(&self) -> &MenuPage<C> {
+        self.get_page(self.get_current_id())
+    }
+
+    pub fn is_open(&self, id: &str) -> bool {
+        self.get_current_id() == id
+    }
+
+    pub fn open(&mut self, id: &'static str) {
+        assert!(self.pages.contains_key(id), "no menu page {}", id);
+        self.stack.push(id);
+    }
+
+    // drops the back-stack, going back from the page returns nowhere
+    pub fn reset(&mut self, id: &'static str) {
+        self.stack = vec![id];
+    }
+
+    // returns the back command of the page that was left
+    pub fn back(&mut self) -> Option<C> {
+        let command = self.get_current_page().back_command.clone();
+        if self.stack.len() > 1 {
+            self.stack.pop();
+        }
+        command
+    }
+
+    pub fn navigate(&mut self, menu_move: MenuMove) {
+        let id = self.get_current_id();
+        let menu = &mut self.get_page_mut(id).menu;
+        if !menu.options.is_empty() {
+            menu.navigate(menu_move);
+        }
+    }
+
+    // submenus open right away, back items and pages without items close like with back
+    pub fn select(&mut self) -> Option<C> {
+        match self.get_current_page().get_selected_target().cloned() {
+            Some(MenuTarget::Command(command)) => Some(command),
+            Some(MenuTarget::Submenu(id)) => {
+                self.open(id);
+                None
+            }
+            Some(MenuTarget::Back) | None => self.back(),
+        }
+    }
+
+    // relabels every item with the target, on all pages
+    pub fn set_label(&mut self, target: &MenuTarget<C>, label: String) {
+        for page in self.pages.values_mut() {
+            for (idx, item_target) in page.targets.iter().enumerate() {
+                if item_target == target {
+                    page.menu.options[idx] = label.clone();
+                }
+            }
+        }
+    }
+}
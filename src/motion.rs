@@ -0,0 +1,140 @@
+use ggez::mint::Point2;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+pub const SINE_DRIFT_AMPLITUDE: f32 = 60.0;
+pub const SINE_DRIFT_FREQUENCY: f32 = 2.0;
+pub const ACCELERATION_PER_SECOND: f32 = 0.5;
+pub const MIN_HORIZONTAL_SPEED: f32 = 40.0;
+pub const MAX_HORIZONTAL_SPEED: f32 = 120.0;
+pub const MIN_FALL_SPEED_FACTOR: f32 = 0.8;
+pub const MAX_FALL_SPEED_FACTOR: f32 = 1.2;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MotionPattern {
+    Linear,
+    SineDrift,
+    DiagonalBounce,
+    Accelerating,
+    SideEntry,
+}
+
+impl MotionPattern {
+    pub const ALL: [MotionPattern; 5] = [
+        MotionPattern::Linear,
+        MotionPattern::SineDrift,
+        MotionPattern::DiagonalBounce,
+        MotionPattern::Accelerating,
+        MotionPattern::SideEntry,
+    ];
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Motion {
+    pub pattern: MotionPattern,
+    // pixels per second, the sign gives the direction
+    pub horizontal_speed: f32,
+    // multiplier for the global falling speed
+    pub fall_speed_factor: f32,
+    // the x the word drifts around or slides towards
+    pub anchor_x: f32,
+    pub elapsed_time: f32,
+}
+
+impl Default for Motion {
+    fn default() -> Self {
+        Self {
+            pattern: MotionPattern::Linear,
+            horizontal_speed: 0.0,
+            fall_speed_factor: 1.0,
+            anchor_x: 0.0,
+            elapsed_time: 0.0,
+        }
+    }
+}
+
+impl Motion {
+    // picks a random pattern out of the allowed ones and returns it along with the x the word
+    // should spawn at
+    pub fn new_random<R: Rng + ?Sized>(
+        patterns: &[MotionPattern],
+        max_x: f32,
+        rng: &mut R,
+    ) -> (Self, f32) {
+        let pattern = patterns
+            .choose(rng)
+            .copied()
+            .unwrap_or(MotionPattern::Linear);
+        let spawn_x = rng.gen_range(0.0..=max_x);
+        if pattern == MotionPattern::Linear {
+            return (Self::default(), spawn_x);
+        }
+
+        let direction = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+        let mut motion = Self {
+            pattern,
+            horizontal_speed: direction
+                * rng.gen_range(MIN_HORIZONTAL_SPEED..=MAX_HORIZONTAL_SPEED),
+            fall_speed_factor: rng.gen_range(MIN_FALL_SPEED_FACTOR..=MAX_FALL_SPEED_FACTOR),
+            anchor_x: spawn_x,
+            elapsed_time: 0.0,
+        };
+        let spawn_x = match pattern {
+            MotionPattern::SineDrift => {
+                motion.anchor_x = spawn_x.clamp(
+                    SINE_DRIFT_AMPLITUDE.min(max_x / 2.0),
+                    (max_x - SINE_DRIFT_AMPLITUDE).max(max_x / 2.0),
+                );
+                motion.anchor_x
+            }
+            // enters from the edge opposite to the direction it slides in
+            MotionPattern::SideEntry if motion.horizontal_speed > 0.0 => 0.0,
+            MotionPattern::SideEntry => max_x,
+            _ => spawn_x,
+        };
+        (motion, spawn_x)
+    }
+
+    pub fn update(
+        &mut self,
+        position: &mut Point2<f32>,
+        fall_distance: f32,
+        last_frame_length: f32,
+        max_x: f32,
+    ) {
+        self.elapsed_time += last_frame_length;
+        let mut fall_distance = fall_distance * self.fall_speed_factor;
+
+        match self.pattern {
+            MotionPattern::Linear => (),
+            MotionPattern::SineDrift => {
+                position.x = self.anchor_x
+                    + SINE_DRIFT_AMPLITUDE * (self.elapsed_time * SINE_DRIFT_FREQUENCY).sin();
+            }
+            MotionPattern::DiagonalBounce => {
+                position.x += self.horizontal_speed * last_frame_length;
+                if position.x <= 0.0 {
+                    self.horizontal_speed = self.horizontal_speed.abs();
+                } else if position.x >= max_x {
+                    self.horizontal_speed = -self.horizontal_speed.abs();
+                }
+            }
+            MotionPattern::Accelerating => {
+                fall_distance *= 1.0 + ACCELERATION_PER_SECOND * self.elapsed_time;
+            }
+            MotionPattern::SideEntry => {
+                let step = self.horizontal_speed * last_frame_length;
+                let distance_left = self.anchor_x - position.x;
+                position.x = if distance_left.abs() <= step.abs() {
+                    self.anchor_x
+                } else {
+                    position.x + step.abs() * distance_left.signum()
+                };
+            }
+        }
+
+        position.x = position.x.clamp(0.0, max_x.max(0.0));
+        position.y += fall_distance;
+    }
+}
@@ -0,0 +1,238 @@
+use std::path::Path;
+
+use ggez::GameResult;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{
+    default_max_lives, GameRules, INITIAL_GAME_SPEED, INITIAL_TIME_UNTIL_NEXT_WORD, MAX_LIVES,
+    SPAWN_INTERVAL_DECAY, SPEED_INCREASE_PER_WORD, STARTING_LIVES, WORD_SCORE,
+};
+use crate::storage;
+use crate::word::DEFAULT_EFFECT_CHANCE;
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+    Insane,
+    Custom,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 5] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Insane,
+        Difficulty::Custom,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            Difficulty::Easy => String::from("EASY"),
+            Difficulty::Normal => String::from("NORMAL"),
+            Difficulty::Hard => String::from("HARD"),
+            Difficulty::Insane => String::from("INSANE"),
+            Difficulty::Custom => String::from("CUSTOM"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct Preset {
+    pub starting_lives: u32,
+    #[serde(default = "default_max_lives")]
+    pub max_lives: u32,
+    pub starting_speed: u32,
+    pub speed_increase_per_word: u32,
+    pub spawn_interval: f32,
+    pub spawn_interval_decay: f32,
+    pub power_up_chance: u32,
+    pub word_score: u32,
+}
+
+impl Default for Preset {
+    fn default() -> Self {
+        Self::get_builtin(Difficulty::Normal)
+    }
+}
+
+impl Preset {
+    pub fn get_builtin(difficulty: Difficulty) -> Preset {
+        match difficulty {
+            Difficulty::Easy => Preset {
+                starting_lives: 5,
+                max_lives: 8,
+                starting_speed: 35,
+                speed_increase_per_word: 3,
+                spawn_interval: 1.4,
+                spawn_interval_decay: 0.0002,
+                power_up_chance: 30,
+                word_score: 5,
+            },
+            Difficulty::Normal | Difficulty::Custom => Preset {
+                starting_lives: STARTING_LIVES,
+                max_lives: MAX_LIVES,
+                starting_speed: INITIAL_GAME_SPEED,
+                speed_increase_per_word: SPEED_INCREASE_PER_WORD,
+                spawn_interval: INITIAL_TIME_UNTIL_NEXT_WORD,
+                spawn_interval_decay: SPAWN_INTERVAL_DECAY,
+                power_up_chance: DEFAULT_EFFECT_CHANCE,
+                word_score: WORD_SCORE,
+            },
+            Difficulty::Hard => Preset {
+                starting_lives: 1,
+                max_lives: 3,
+                starting_speed: 70,
+                speed_increase_per_word: 6,
+                spawn_interval: 0.8,
+                spawn_interval_decay: 0.0005,
+                power_up_chance: 15,
+                word_score: 15,
+            },
+            Difficulty::Insane => Preset {
+                starting_lives: 0,
+                max_lives: 1,
+                starting_speed: 100,
+                speed_increase_per_word: 8,
+                spawn_interval: 0.6,
+                spawn_interval_decay: 0.001,
+                power_up_chance: 10,
+                word_score: 25,
+            },
+        }
+    }
+
+    pub fn get_rules(&self) -> GameRules {
+        GameRules {
+            starting_lives: self.starting_lives.min(self.max_lives),
+            max_lives: self.max_lives,
+            initial_game_speed: self.starting_speed,
+            speed_increase_per_word: self.speed_increase_per_word,
+            initial_spawn_interval: self.spawn_interval,
+            spawn_interval_decay: self.spawn_interval_decay,
+            effect_chance: self.power_up_chance,
+            word_score: self.word_score,
+            ..GameRules::default()
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PresetField {
+    StartingLives,
+    MaxLives,
+    StartingSpeed,
+    SpeedIncreasePerWord,
+    SpawnInterval,
+    SpawnIntervalDecay,
+    PowerUpChance,
+    WordScore,
+}
+
+impl PresetField {
+    pub const ALL: [PresetField; 8] = [
+        PresetField::StartingLives,
+        PresetField::MaxLives,
+        PresetField::StartingSpeed,
+        PresetField::SpeedIncreasePerWord,
+        PresetField::SpawnInterval,
+        PresetField::SpawnIntervalDecay,
+        PresetField::PowerUpChance,
+        PresetField::WordScore,
+    ];
+
+    pub fn get_label(&self, preset: &Preset) -> String {
+        match self {
+            PresetField::StartingLives => format!("LIVES: {}", preset.starting_lives),
+            PresetField::MaxLives => format!("MAX LIVES: {}", preset.max_lives),
+            PresetField::StartingSpeed => format!("SPEED: {}", preset.starting_speed),
+            PresetField::SpeedIncreasePerWord => {
+                format!("SPEED UP: {}", preset.speed_increase_per_word)
+            }
+            PresetField::SpawnInterval => format!("SPAWN EVERY: {:.1}S", preset.spawn_interval),
+            PresetField::SpawnIntervalDecay => {
+                format!("SPAWN DECAY: {:.2}MS", preset.spawn_interval_decay * 1000.0)
+            }
+            PresetField::PowerUpChance => format!("POWER-UPS: {}%", preset.power_up_chance),
+            PresetField::WordScore => format!("SCORE: {}", preset.word_score),
+        }
+    }
+
+    // steps the field up and wraps around to its minimum once the maximum is passed
+    pub fn cycle(&self, preset: &mut Preset) {
+        fn cycle_u32(value: u32, min: u32, max: u32, step: u32) -> u32 {
+            if value + step > max {
+                min
+            } else {
+                value + step
+            }
+        }
+        fn cycle_f32(value: f32, min: f32, max: f32, step: f32) -> f32 {
+            let next = ((value + step) / step).round() * step;
+            if next > max + step / 2.0 {
+                min
+            } else {
+                next
+            }
+        }
+
+        match self {
+            PresetField::StartingLives => {
+                preset.starting_lives = cycle_u32(preset.starting_lives, 0, 9, 1)
+            }
+            PresetField::MaxLives => preset.max_lives = cycle_u32(preset.max_lives, 1, 9, 1),
+            PresetField::StartingSpeed => {
+                preset.starting_speed = cycle_u32(preset.starting_speed, 20, 200, 10)
+            }
+            PresetField::SpeedIncreasePerWord => {
+                preset.speed_increase_per_word = cycle_u32(preset.speed_increase_per_word, 0, 10, 1)
+            }
+            PresetField::SpawnInterval => {
+                preset.spawn_interval = cycle_f32(preset.spawn_interval, 0.4, 2.0, 0.1)
+            }
+            PresetField::SpawnIntervalDecay => {
+                preset.spawn_interval_decay =
+                    cycle_f32(preset.spawn_interval_decay, 0.0, 0.002, 0.0001)
+            }
+            PresetField::PowerUpChance => {
+                preset.power_up_chance = cycle_u32(preset.power_up_chance, 0, 60, 5)
+            }
+            PresetField::WordScore => preset.word_score = cycle_u32(preset.word_score, 5, 50, 5),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PresetSettings {
+    pub difficulty: Difficulty,
+    pub custom_preset: Preset,
+}
+
+impl Default for PresetSettings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::Normal,
+            custom_preset: Preset::default(),
+        }
+    }
+}
+
+impl PresetSettings {
+    pub fn load(path: &Path) -> PresetSettings {
+        storage::load_toml_or_default(path, "difficulty presets")
+    }
+
+    pub fn save(&self, path: &Path) -> GameResult {
+        storage::save_toml(path, self)
+    }
+
+    pub fn get_preset(&self) -> Preset {
+        match self.difficulty {
+            Difficulty::Custom => self.custom_preset,
+            difficulty => Preset::get_builtin(difficulty),
+        }
+    }
+}
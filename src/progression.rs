@@ -0,0 +1,180 @@
+use std::env;
+use std::path::{Path, PathBuf};
+
+use ggez::GameResult;
+use serde::{Deserialize, Serialize};
+
+use crate::game::GameMode;
+use crate::preset::Difficulty;
+use crate::results::{GameSummary, ResultsHistory};
+use crate::storage;
+
+pub const DEFAULT_PROFILE_NAME: &str = "player";
+// xp needed to get from level n to n + 1 is n times this
+pub const XP_PER_LEVEL: u32 = 100;
+// score points per xp point before accuracy and mode are applied
+pub const SCORE_PER_XP: u32 = 10;
+
+// content that is only available from a certain player level on
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Unlock {
+    Font(&'static str),
+    Theme(&'static str),
+    Mode(GameMode),
+    Difficulty(Difficulty),
+}
+
+pub const UNLOCKS: [(u32, Unlock); 5] = [
+    (2, Unlock::Font("BungeeShade")),
+    (3, Unlock::Theme("crimson")),
+    (4, Unlock::Mode(GameMode::Blind)),
+    (5, Unlock::Difficulty(Difficulty::Insane)),
+    (6, Unlock::Theme("midnight")),
+];
+
+// the level the content unlocks at, 1 for content that is always available
+pub fn get_unlock_level(unlock: Unlock) -> u32 {
+    UNLOCKS
+        .iter()
+        .find(|(_, locked)| *locked == unlock)
+        .map_or(1, |(level, _)| *level)
+}
+
+// themes and fonts are loaded from files, so they are looked up by name
+pub fn get_theme_unlock_level(name: &str) -> u32 {
+    UNLOCKS
+        .iter()
+        .find(|(_, unlock)| matches!(unlock, Unlock::Theme(theme) if *theme == name))
+        .map_or(1, |(level, _)| *level)
+}
+
+pub fn get_font_unlock_level(name: &str) -> u32 {
+    UNLOCKS
+        .iter()
+        .find(|(_, unlock)| matches!(unlock, Unlock::Font(font) if *font == name))
+        .map_or(1, |(level, _)| *level)
+}
+
+// harder modes are worth more xp
+pub fn get_mode_xp_percent(mode: GameMode) -> u32 {
+    match mode {
+        GameMode::Endless => 100,
+        GameMode::Campaign => 110,
+        GameMode::Waves => 120,
+        GameMode::Blind => 150,
+    }
+}
+
+pub fn calculate_xp(score: u32, accuracy: f32, mode: GameMode) -> u32 {
+    let xp = (score / SCORE_PER_XP) as f32 * accuracy.clamp(0.0, 1.0);
+    (xp * get_mode_xp_percent(mode) as f32 / 100.0).round() as u32
+}
+
+pub fn get_profile_name() -> String {
+    let name = env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .unwrap_or_default();
+    sanitize_profile_name(&name)
+}
+
+// the name becomes a file name, so anything that could point outside the profiles directory
+// is dropped
+pub fn sanitize_profile_name(name: &str) -> String {
+    let name = name
+        .chars()
+        .filter(|letter| !matches!(letter, '/' | '\\' | ':') && !letter.is_control())
+        .collect::<String>()
+        .replace("..", "");
+    let name = name.trim();
+    if name.is_empty() || name == "." {
+        String::from(DEFAULT_PROFILE_NAME)
+    } else {
+        String::from(name)
+    }
+}
+
+pub fn get_profile_path(data_dir: &Path, name: &str) -> PathBuf {
+    data_dir.join("profiles").join(format!("{}.toml", name))
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct Profile {
+    pub xp: u32,
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+    // modes and difficulties were free before player levels existed, so the ones already
+    // played stay unlocked
+    #[serde(skip)]
+    pub used_unlocks: Vec<Unlock>,
+}
+
+impl Profile {
+    pub fn load(path: &Path) -> Profile {
+        let mut profile: Profile = storage::load_toml_or_default(path, "profile");
+        profile.path = Some(path.to_path_buf());
+        profile
+    }
+
+    pub fn save(&self) -> GameResult {
+        match &self.path {
+            Some(path) => storage::save_toml(path, self),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_level(&self) -> u32 {
+        self.get_level_progress().0
+    }
+
+    // the level, the xp gathered within it and the xp it takes to reach the next one
+    pub fn get_level_progress(&self) -> (u32, u32, u32) {
+        let mut level = 1;
+        let mut xp_left = self.xp;
+        while xp_left >= level * XP_PER_LEVEL {
+            xp_left -= level * XP_PER_LEVEL;
+            level += 1;
+        }
+        (level, xp_left, level * XP_PER_LEVEL)
+    }
+
+    // returns how many levels the xp was worth
+    pub fn add_xp(&mut self, xp: u32) -> u32 {
+        let level_before = self.get_level();
+        self.xp += xp;
+        if let Err(err) = self.save() {
+            eprintln!("could not save the player profile: {}", err);
+        }
+        self.get_level() - level_before
+    }
+
+    pub fn keep_used_content(&mut self, history: &ResultsHistory) {
+        let results: Vec<&GameSummary> = history
+            .results
+            .iter()
+            .filter(|result| result.level.is_none())
+            .collect();
+        self.used_unlocks = UNLOCKS
+            .iter()
+            .map(|(_, unlock)| *unlock)
+            .filter(|unlock| match unlock {
+                Unlock::Mode(mode) => results.iter().any(|result| result.mode == mode.get_label()),
+                Unlock::Difficulty(difficulty) => results
+                    .iter()
+                    .any(|result| result.difficulty == *difficulty),
+                Unlock::Font(_) | Unlock::Theme(_) => false,
+            })
+            .collect();
+    }
+
+    pub fn is_unlocked(&self, unlock: Unlock) -> bool {
+        self.get_level() >= get_unlock_level(unlock) || self.used_unlocks.contains(&unlock)
+    }
+
+    pub fn is_font_unlocked(&self, name: &str) -> bool {
+        self.get_level() >= get_font_unlock_level(name)
+    }
+
+    pub fn is_theme_unlocked(&self, name: &str) -> bool {
+        self.get_level() >= get_theme_unlock_level(name)
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::{Path, PathBuf};
+
+use ggez::GameResult;
+use serde::{Deserialize, Serialize};
+
+use crate::preset::{Difficulty, Preset};
+use crate::scoring::WordScore;
+use crate::storage;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct GameSummary {
+    pub mode: String,
+    // campaign levels bring their own rules, so their results ignore the difficulty preset
+    #[serde(default)]
+    pub level: Option<String>,
+    pub difficulty: Difficulty,
+    pub score: u32,
+    pub completed_words: u32,
+    #[serde(default)]
+    pub best_streak: u32,
+    #[serde(default)]
+    pub misremembered_words: Vec<String>,
+    #[serde(default)]
+    pub xp: u32,
+    // toml writes tables last, so the preset table stays after the plain values
+    pub preset: Preset,
+    // only shown after the game, not kept in the history
+    #[serde(skip)]
+    pub word_scores: Vec<WordScore>,
+}
+
+impl GameSummary {
+    pub fn is_comparable_with(&self, other: &GameSummary) -> bool {
+        self.mode == other.mode
+            && self.level == other.level
+            && (self.level.is_some()
+                || (self.difficulty == other.difficulty && self.preset == other.preset))
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+pub struct ResultsHistory {
+    pub results: Vec<GameSummary>,
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl ResultsHistory {
+    pub fn load(path: &Path) -> ResultsHistory {
+        let mut history: ResultsHistory = storage::load_toml_or_default(path, "game results");
+        history.path = Some(path.to_path_buf());
+        history
+    }
+
+    pub fn save(&self) -> GameResult {
+        match &self.path {
+            Some(path) => storage::save_toml(path, self),
+            None => Ok(()),
+        }
+    }
+
+    pub fn get_best_score(&self, summary: &GameSummary) -> Option<u32> {
+        self.results
+            .iter()
+            .filter(|result| result.is_comparable_with(summary))
+            .map(|result| result.score)
+            .max()
+    }
+
+    pub fn add(&mut self, summary: GameSummary) {
+        self.results.push(summary);
+        if let Err(err) = self.save() {
+            eprintln!("could not save the game results: {}", err);
+        }
+    }
+}
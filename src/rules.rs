@@ -0,0 +1,72 @@
+use crate::word::DEFAULT_EFFECT_CHANCE;
+
+pub const WORD_SCORE: u32 = 10;
+pub const DEFAULT_HAZARD_CHANCE: u32 = 10;
+pub const STARTING_LIVES: u32 = 3;
+pub const MAX_LIVES: u32 = 5;
+
+pub const INITIAL_TIME_UNTIL_NEXT_WORD: f32 = 1.0;
+pub const INITIAL_GAME_SPEED: u32 = 50;
+pub const SPEED_INCREASE_PER_WORD: u32 = 5;
+pub const SPAWN_INTERVAL_DECAY: f32 = 0.0003;
+pub const MIN_SPAWN_INTERVAL: f32 = 0.2;
+
+// serde defaults for the lives of presets and levels
+pub fn default_starting_lives() -> u32 {
+    STARTING_LIVES
+}
+
+pub fn default_max_lives() -> u32 {
+    MAX_LIVES
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GameRules {
+    pub starting_lives: u32,
+    pub max_lives: u32,
+    pub initial_game_speed: u32,
+    pub speed_increase_per_word: u32,
+    pub max_game_speed: Option<u32>,
+    pub initial_spawn_interval: f32,
+    pub spawn_interval_decay: f32,
+    pub min_spawn_interval: f32,
+    pub effect_chance: u32,
+    pub hazard_chance: u32,
+    pub word_score: u32,
+}
+
+impl Default for GameRules {
+    fn default() -> Self {
+        Self {
+            starting_lives: STARTING_LIVES,
+            max_lives: MAX_LIVES,
+            initial_game_speed: INITIAL_GAME_SPEED,
+            speed_increase_per_word: SPEED_INCREASE_PER_WORD,
+            max_game_speed: None,
+            initial_spawn_interval: INITIAL_TIME_UNTIL_NEXT_WORD,
+            spawn_interval_decay: SPAWN_INTERVAL_DECAY,
+            min_spawn_interval: MIN_SPAWN_INTERVAL,
+            effect_chance: DEFAULT_EFFECT_CHANCE,
+            hazard_chance: DEFAULT_HAZARD_CHANCE,
+            word_score: WORD_SCORE,
+        }
+    }
+}
+
+impl GameRules {
+    pub fn get_increased_speed(&self, game_speed: u32) -> u32 {
+        let increased_speed = game_speed + self.speed_increase_per_word;
+        match self.max_game_speed {
+            Some(max_game_speed) => increased_speed.min(max_game_speed.max(game_speed)),
+            None => increased_speed,
+        }
+    }
+
+    pub fn get_decreased_spawn_interval(&self, spawn_interval: f32) -> f32 {
+        if spawn_interval > self.min_spawn_interval {
+            spawn_interval - self.spawn_interval_decay
+        } else {
+            spawn_interval
+        }
+    }
+}
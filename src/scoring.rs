@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use unicode_segmentation::UnicodeSegmentation;
+
+// a word of this many letters is worth exactly the base score before bonuses
+pub const REFERENCE_WORD_LENGTH: u32 = 5;
+// bonus points per letter difficulty point, in percent of the base score
+pub const LETTER_DIFFICULTY_PERCENT: u32 = 10;
+// the highest reaction and danger bonuses, in percent of the base score
+pub const MAX_REACTION_BONUS_PERCENT: u32 = 50;
+pub const MAX_DANGER_BONUS_PERCENT: u32 = 50;
+// time to finish a word before the reaction bonus runs out
+pub const REACTION_TIME_BASE: f32 = 1.0;
+pub const REACTION_TIME_PER_LETTER: f32 = 0.5;
+// words finished above this part of the screen get no danger bonus
+pub const DANGER_ZONE_START: f32 = 0.5;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct WordScore {
+    pub word: String,
+    pub length: u32,
+    pub letters: u32,
+    pub reaction: u32,
+    pub danger: u32,
+    pub multiplier: u32,
+    pub total: u32,
+}
+
+impl WordScore {
+    // height is how far down the screen the word was finished, between 0 and 1
+    pub fn new(word: &str, base_score: u32, reaction_time: f32, height: f32) -> Self {
+        let letters_count = word.graphemes(true).count() as u32;
+        let difficulty: u32 = word.graphemes(true).map(get_letter_difficulty).sum();
+        let reaction_limit = REACTION_TIME_BASE + REACTION_TIME_PER_LETTER * letters_count as f32;
+        let reaction_fraction = (1.0 - reaction_time / reaction_limit).clamp(0.0, 1.0);
+        let danger_fraction =
+            ((height - DANGER_ZONE_START) / (1.0 - DANGER_ZONE_START)).clamp(0.0, 1.0);
+        let mut score = Self {
+            word: String::from(word),
+            length: base_score * letters_count / REFERENCE_WORD_LENGTH,
+            letters: base_score * difficulty * LETTER_DIFFICULTY_PERCENT / 100,
+            reaction: get_percent_of(base_score, MAX_REACTION_BONUS_PERCENT, reaction_fraction),
+            danger: get_percent_of(base_score, MAX_DANGER_BONUS_PERCENT, danger_fraction),
+            multiplier: 1,
+            total: 0,
+        };
+        score.total = score.get_points();
+        score
+    }
+
+    // the points before any multiplier
+    pub fn get_points(&self) -> u32 {
+        self.length + self.letters + self.reaction + self.danger
+    }
+
+    // scales the points, e.g. by the accuracy of a word typed blind; every part is scaled so the
+    // breakdown still adds up to the total
+    pub fn scale(&mut self, factor: f32) {
+        for points in [
+            &mut self.length,
+            &mut self.letters,
+            &mut self.reaction,
+            &mut self.danger,
+        ] {
+            *points = (*points as f32 * factor).round() as u32;
+        }
+        self.total = self.get_points() * self.multiplier;
+    }
+
+    pub fn multiply(&mut self, multiplier: u32) {
+        self.multiplier *= multiplier;
+        self.total *= multiplier;
+    }
+
+    pub fn get_label(&self) -> String {
+        let mut label = format!(
+            "{} {}+{}+{}+{}",
+            self.word.to_uppercase(),
+            self.length,
+            self.letters,
+            self.reaction,
+            self.danger
+        );
+        if self.multiplier > 1 {
+            label += &format!(" X{}", self.multiplier);
+        }
+        label + &format!(" = {}", self.total)
+    }
+}
+
+// rarely used letters are harder to find on the keyboard
+pub fn get_letter_difficulty(letter: &str) -> u32 {
+    match letter.to_lowercase().as_str() {
+        "j" | "q" | "x" | "z" => 3,
+        "k" | "v" | "b" => 2,
+        letter if !letter.is_ascii() => 2,
+        _ => 0,
+    }
+}
+
+fn get_percent_of(value: u32, percent: u32, fraction: f32) -> u32 {
+    (value as f32 * percent as f32 / 100.0 * fraction).round() as u32
+}
@@ -0,0 +1,124 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ggez::{GameError, GameResult};
+use serde::{Deserialize, Serialize};
+use toml::value::{Table, Value};
+
+use crate::bindings::Bindings;
+use crate::layout::{
+    get_nearest_option, DisplayMode, DEFAULT_UI_SCALE, DEFAULT_WORD_SIZE, UI_SCALES, WORD_SIZES,
+};
+use crate::storage;
+use crate::theme::DEFAULT_THEME_NAME;
+
+pub const SETTINGS_FILE: &str = "settings.toml";
+pub const SETTINGS_VERSION: u32 = 2;
+
+// files written before the version was stored count as the first version
+const UNVERSIONED_SETTINGS_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Settings {
+    pub version: u32,
+    pub words_font: String,
+    pub theme: String,
+    pub spectator: bool,
+    pub hide_typed_letters: bool,
+    // percent, see the layout module
+    pub ui_scale: u32,
+    pub word_size: u32,
+    pub display_mode: DisplayMode,
+    pub bindings: Bindings,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            words_font: String::from("GravitasOne"),
+            theme: String::from(DEFAULT_THEME_NAME),
+            spectator: false,
+            hide_typed_letters: false,
+            ui_scale: DEFAULT_UI_SCALE,
+            word_size: DEFAULT_WORD_SIZE,
+            display_mode: DisplayMode::default(),
+            bindings: Bindings::default(),
+        }
+    }
+}
+
+impl Settings {
+    // unknown keys are ignored and missing ones fall back to their defaults
+    pub fn from_toml_str(contents: &str) -> GameResult<Settings> {
+        let mut value: Value = toml::from_str(contents).map_err(|err| {
+            GameError::ResourceLoadError(format!("invalid settings file: {}", err))
+        })?;
+        migrate(&mut value);
+        let mut settings: Settings = value.try_into().map_err(|err| {
+            GameError::ResourceLoadError(format!("invalid settings file: {}", err))
+        })?;
+        settings.ui_scale = get_nearest_option(&UI_SCALES, settings.ui_scale);
+        settings.word_size = get_nearest_option(&WORD_SIZES, settings.word_size);
+        Ok(settings)
+    }
+
+    // a missing file gives the defaults, a broken one is reported and replaced by them
+    pub fn load(path: &Path) -> Settings {
+        let Ok(contents) = fs::read_to_string(path) else {
+            return Settings::default();
+        };
+        Settings::from_toml_str(&contents).unwrap_or_else(|err| {
+            eprintln!(
+                "could not load the settings from {}: {}",
+                path.display(),
+                err
+            );
+            Settings::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> GameResult {
+        storage::save_toml(path, self)
+    }
+}
+
+pub fn get_settings_path() -> Option<PathBuf> {
+    storage::get_config_dir().map(|config_dir| config_dir.join(SETTINGS_FILE))
+}
+
+// every entry turns the settings of one version into the next, the first one upgrades version 1
+const MIGRATIONS: [fn(&mut Table); (SETTINGS_VERSION - 1) as usize] = [migrate_bg_color_to_theme];
+
+// version 1 only had a background colour, the colours became the themes of the same name
+fn migrate_bg_color_to_theme(table: &mut Table) {
+    if let Some(Value::String(bg_color)) = table.remove("bg_color") {
+        table.insert(
+            String::from("theme"),
+            Value::String(bg_color.to_lowercase()),
+        );
+    }
+}
+
+// newer versions than the known one are read as they are
+fn migrate(value: &mut Value) {
+    let Some(table) = value.as_table_mut() else {
+        return;
+    };
+    let version = table
+        .get("version")
+        .and_then(Value::as_integer)
+        .map_or(UNVERSIONED_SETTINGS_VERSION, |version| {
+            version.max(1) as u32
+        });
+    for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+        migration(table);
+    }
+    if version < SETTINGS_VERSION {
+        table.insert(
+            String::from("version"),
+            Value::Integer(SETTINGS_VERSION as i64),
+        );
+    }
+}
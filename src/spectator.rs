@@ -0,0 +1,159 @@
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, SyncSender};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use tungstenite::{Message, WebSocket};
+
+use crate::word::{Word, WordEffect};
+
+pub const DEFAULT_SPECTATOR_PORT: u16 = 9001;
+pub const SNAPSHOT_INTERVAL: f32 = 0.5;
+// events waiting for the broadcast thread, newer ones are dropped while it is full
+const EVENT_QUEUE_SIZE: usize = 256;
+// connected clients and running handshakes together, connections over it are closed right away
+pub const MAX_SPECTATORS: usize = 8;
+// a client that does not finish the handshake or take a message in time is dropped
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+const CLIENT_WRITE_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct WordSnapshot {
+    pub value: String,
+    pub progress_index: usize,
+    pub x: f32,
+    pub y: f32,
+    pub effect: Option<WordEffect>,
+}
+
+impl From<&Word> for WordSnapshot {
+    fn from(word: &Word) -> Self {
+        Self {
+            value: word.value.clone(),
+            progress_index: word.progress_index,
+            x: word.position.x,
+            y: word.position.y,
+            effect: word.effect,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct GameSnapshot {
+    pub score: u32,
+    pub life_points: u32,
+    pub game_speed: u32,
+    pub words: Vec<WordSnapshot>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SpectatorEvent {
+    GameStarted,
+    WordSpawned {
+        word: WordSnapshot,
+    },
+    LetterTyped {
+        letter: char,
+        word: String,
+        progress_index: usize,
+    },
+    // the points of the completed word, the total score comes with the snapshots
+    WordCompleted {
+        word: String,
+        score: u32,
+    },
+    LifeLost {
+        life_points: u32,
+    },
+    EffectActivated {
+        effect: WordEffect,
+    },
+    GameOver {
+        score: u32,
+    },
+    Snapshot(GameSnapshot),
+}
+
+impl SpectatorEvent {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+pub struct SpectatorServer {
+    port: u16,
+    sender: SyncSender<String>,
+}
+
+impl SpectatorServer {
+    pub fn start(port: u16) -> io::Result<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        listener.set_nonblocking(true)?;
+        let port = listener.local_addr()?.port();
+
+        let (sender, receiver) = mpsc::sync_channel(EVENT_QUEUE_SIZE);
+        thread::spawn(move || run_broadcast_loop(listener, receiver));
+
+        Ok(Self { port, sender })
+    }
+
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    pub fn broadcast(&self, event: &SpectatorEvent) {
+        // the game never waits for spectators, a full queue drops the event
+        let _ = self.sender.try_send(event.to_json());
+    }
+}
+
+// every handshake runs on its own thread so a silent client cannot hold up the others, returns
+// how many were started; each one sends back its client or None when it failed
+fn accept_pending_clients(
+    listener: &TcpListener,
+    client_sender: &Sender<Option<WebSocket<TcpStream>>>,
+    free_slots: usize,
+) -> usize {
+    let mut started = 0;
+    while let Ok((stream, _)) = listener.accept() {
+        if started == free_slots {
+            continue;
+        }
+        let client_sender = client_sender.clone();
+        thread::spawn(move || {
+            let _ = client_sender.send(shake_hands(stream));
+        });
+        started += 1;
+    }
+    started
+}
+
+fn shake_hands(stream: TcpStream) -> Option<WebSocket<TcpStream>> {
+    stream.set_nonblocking(false).ok()?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT)).ok()?;
+    stream.set_write_timeout(Some(CLIENT_WRITE_TIMEOUT)).ok()?;
+    tungstenite::accept(stream).ok()
+}
+
+fn run_broadcast_loop(listener: TcpListener, receiver: Receiver<String>) {
+    let (client_sender, client_receiver) = mpsc::channel();
+    let mut clients: Vec<WebSocket<TcpStream>> = Vec::new();
+    let mut pending_handshakes = 0;
+    loop {
+        let free_slots = MAX_SPECTATORS - clients.len() - pending_handshakes;
+        pending_handshakes += accept_pending_clients(&listener, &client_sender, free_slots);
+        for client in client_receiver.try_iter() {
+            pending_handshakes -= 1;
+            clients.extend(client);
+        }
+        match receiver.recv_timeout(Duration::from_millis(50)) {
+            Ok(message) => clients
+                .retain_mut(|client| client.write_message(Message::Text(message.clone())).is_ok()),
+            Err(RecvTimeoutError::Timeout) => (),
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
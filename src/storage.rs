@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+use directories::ProjectDirs;
+use ggez::{GameError, GameResult};
+use serde::de::{DeserializeOwned, Error, IntoDeserializer};
+use serde::{Deserialize, Deserializer, Serialize};
+
+pub const GAME_ID: &str = "shooter";
+pub const GAME_AUTHOR: &str = "FMI";
+
+pub fn get_data_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", GAME_AUTHOR, GAME_ID).map(|dirs| dirs.data_dir().to_path_buf())
+}
+
+pub fn get_config_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", GAME_AUTHOR, GAME_ID).map(|dirs| dirs.config_dir().to_path_buf())
+}
+
+// a missing file is a first run, a broken one is reported before falling back to the defaults
+pub fn load_toml_or_default<T: DeserializeOwned + Default>(path: &Path, name: &str) -> T {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return T::default();
+    };
+    toml::from_str(&contents).unwrap_or_else(|err| {
+        eprintln!(
+            "could not load the {} from {}: {}",
+            name,
+            path.display(),
+            err
+        );
+        T::default()
+    })
+}
+
+pub fn save_toml<T: Serialize>(path: &Path, value: &T) -> GameResult {
+    let contents = toml::to_string(value).map_err(|err| {
+        GameError::CustomError(format!("could not serialize {}: {}", path.display(), err))
+    })?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+// toml tables only have string keys, so maps keyed by an enum parse the names by hand
+pub fn deserialize_enum_map<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: DeserializeOwned + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    HashMap::<String, V>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, value)| {
+            let key = K::deserialize(name.as_str().into_deserializer())
+                .map_err(|err: serde::de::value::Error| D::Error::custom(err))?;
+            Ok((key, value))
+        })
+        .collect()
+}
@@ -0,0 +1,42 @@
+// words in a row needed for each multiplier, from the highest down
+pub const STREAK_MULTIPLIERS: [(u32, u32); 3] = [(30, 4), (20, 3), (10, 2)];
+pub const STREAK_BREAK_ANIMATION_LENGTH: f32 = 1.2;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Streak {
+    pub current: u32,
+    pub best: u32,
+    // the streak that was just lost and how long it is still shown
+    pub broken: Option<(u32, f32)>,
+}
+
+impl Streak {
+    pub fn register_completed_word(&mut self) {
+        self.current += 1;
+        self.best = self.best.max(self.current);
+    }
+
+    pub fn break_streak(&mut self) {
+        if self.current > 0 {
+            self.broken = Some((self.current, STREAK_BREAK_ANIMATION_LENGTH));
+        }
+        self.current = 0;
+    }
+
+    pub fn get_multiplier(&self) -> u32 {
+        STREAK_MULTIPLIERS
+            .iter()
+            .find(|(threshold, _)| self.current >= *threshold)
+            .map_or(1, |(_, multiplier)| *multiplier)
+    }
+
+    pub fn update_break_animation(&mut self, last_frame_length: f32) {
+        if let Some((streak, time_left)) = self.broken {
+            self.broken = if time_left - last_frame_length <= 0.0 {
+                None
+            } else {
+                Some((streak, time_left - last_frame_length))
+            };
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use ggez::graphics::Color;
+use ggez::{Context, GameError, GameResult};
+use serde::Deserialize;
+
+use crate::effects::EffectTable;
+use crate::storage;
+use crate::word::WordEffect;
+
+pub const THEMES_DIR: &str = "/themes";
+pub const DEFAULT_THEME_NAME: &str = "purple";
+
+// the default theme is built in, see Theme::default
+const BUNDLED_THEMES: [&str; 5] = [
+    include_str!("../resources/themes/crimson.toml"),
+    include_str!("../resources/themes/daylight.toml"),
+    include_str!("../resources/themes/green.toml"),
+    include_str!("../resources/themes/midnight.toml"),
+    include_str!("../resources/themes/paper.toml"),
+];
+
+// every colour is RGBA, a theme file only needs the ones that differ from the default theme
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct Theme {
+    // left empty by theme files without a name, see from_theme_file
+    #[serde(default)]
+    pub name: String,
+    pub is_light: bool,
+    pub background: [f32; 4],
+    // menu options and other plain text
+    pub text: [f32; 4],
+    // the selected menu option and other things that stand out
+    pub highlight: [f32; 4],
+    // locked options and empty slots
+    pub dimmed: [f32; 4],
+    // words without an effect
+    pub word: [f32; 4],
+    pub score: [f32; 4],
+    pub lives: [f32; 4],
+    pub wave: [f32; 4],
+    pub streak: [f32; 4],
+    // lost games, lost lives and broken streaks
+    pub danger: [f32; 4],
+    pub explosion: [f32; 4],
+    pub toast: [f32; 4],
+    pub overlay: [f32; 4],
+    pub bar_background: [f32; 4],
+    pub fog: [f32; 4],
+    // replaces the colours of effect words that would be hard to read on this background
+    #[serde(deserialize_with = "storage::deserialize_enum_map")]
+    pub effect_colors: HashMap<WordEffect, [f32; 4]>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            name: String::from(DEFAULT_THEME_NAME),
+            is_light: false,
+            background: [0.19, 0.2, 0.45, 0.65],
+            text: [1.0, 1.0, 1.0, 1.0],
+            highlight: [1.0, 1.0, 0.0, 1.0],
+            dimmed: [1.0, 1.0, 1.0, 0.35],
+            word: [1.0, 1.0, 1.0, 1.0],
+            score: [0.8, 0.4, 0.17, 0.88],
+            lives: [0.0, 1.0, 0.0, 1.0],
+            wave: [1.0, 1.0, 0.0, 1.0],
+            streak: [0.95, 0.75, 0.2, 0.9],
+            danger: [1.0, 0.0, 0.0, 1.0],
+            explosion: [1.0, 0.3, 0.1, 1.0],
+            toast: [1.0, 0.85, 0.0, 1.0],
+            overlay: [0.0, 0.0, 0.0, 0.7],
+            bar_background: [0.0, 0.0, 0.0, 0.5],
+            fog: [0.6, 0.6, 0.65, 0.97],
+            effect_colors: HashMap::new(),
+        }
+    }
+}
+
+impl Theme {
+    pub fn from_toml_str(contents: &str) -> GameResult<Theme> {
+        toml::from_str(contents)
+            .map_err(|err| GameError::ResourceLoadError(format!("invalid theme file: {}", err)))
+    }
+
+    // a theme file without a name is named after the file, so it never replaces a bundled theme
+    // by accident
+    pub fn from_theme_file(contents: &str, path: &Path) -> GameResult<Theme> {
+        let mut theme = Theme::from_toml_str(contents)?;
+        if theme.name.is_empty() {
+            theme.name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        if theme.name.is_empty() {
+            return Err(GameError::ResourceLoadError(String::from(
+                "the theme has no name",
+            )));
+        }
+        Ok(theme)
+    }
+
+    pub fn get_label(&self) -> String {
+        let brightness = if self.is_light { "LIGHT" } else { "DARK" };
+        format!("{} ({})", self.name.to_uppercase(), brightness)
+    }
+
+    pub fn get_word_color(&self, effect: Option<WordEffect>, effect_table: &EffectTable) -> Color {
+        match effect {
+            Some(effect) => self.get_effect_color(effect, effect_table),
+            None => Color::from(self.word),
+        }
+    }
+
+    pub fn get_effect_color(&self, effect: WordEffect, effect_table: &EffectTable) -> Color {
+        match self.effect_colors.get(&effect) {
+            Some(color) => Color::from(*color),
+            None => effect_table.get_color(Some(effect)),
+        }
+    }
+}
+
+// the colour with its opacity scaled, used for fading and faded variants
+pub fn with_alpha(color: [f32; 4], alpha: f32) -> Color {
+    Color::new(color[0], color[1], color[2], color[3] * alpha)
+}
+
+pub fn get_bundled_themes() -> &'static Vec<Theme> {
+    static BUNDLED: OnceLock<Vec<Theme>> = OnceLock::new();
+    BUNDLED.get_or_init(|| {
+        let mut themes = BUNDLED_THEMES
+            .iter()
+            .map(|contents| Theme::from_toml_str(contents).unwrap())
+            .collect();
+        add_default_theme(&mut themes);
+        themes
+    })
+}
+
+// a theme file with the default name replaces the built-in theme
+fn add_default_theme(themes: &mut Vec<Theme>) {
+    if !themes.iter().any(|theme| theme.name == DEFAULT_THEME_NAME) {
+        themes.push(Theme::default());
+    }
+}
+
+pub fn get_default_theme() -> &'static Theme {
+    static DEFAULT_THEME: OnceLock<Theme> = OnceLock::new();
+    DEFAULT_THEME.get_or_init(Theme::default)
+}
+
+pub fn load_themes(ctx: &Context) -> GameResult<Vec<Theme>> {
+    let mut paths: Vec<PathBuf> = ctx
+        .fs
+        .read_dir(THEMES_DIR)?
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    let mut themes = Vec::new();
+    for path in paths {
+        match load_theme(ctx, &path) {
+            Ok(theme) => themes.push(theme),
+            Err(err) => eprintln!("skipping the theme {}: {}", path.display(), err),
+        }
+    }
+    add_default_theme(&mut themes);
+    Ok(themes)
+}
+
+fn load_theme(ctx: &Context, path: &Path) -> GameResult<Theme> {
+    let mut contents = String::new();
+    ctx.fs.open(path)?.read_to_string(&mut contents)?;
+    Theme::from_theme_file(&contents, path)
+}
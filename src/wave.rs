@@ -0,0 +1,102 @@
+pub const WORDS_PER_WAVE: u32 = 15;
+pub const INTERMISSION_LENGTH: f32 = 15.0;
+pub const WAVE_SPEED_INCREASE: u32 = 10;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Upgrade {
+    ExtraLife,
+    SlowerBaseSpeed,
+    LongerPowerUps,
+    HigherEffectChance,
+}
+
+impl Upgrade {
+    pub const ALL: [Upgrade; 4] = [
+        Upgrade::ExtraLife,
+        Upgrade::SlowerBaseSpeed,
+        Upgrade::LongerPowerUps,
+        Upgrade::HigherEffectChance,
+    ];
+
+    pub fn cost(&self) -> u32 {
+        match self {
+            Upgrade::ExtraLife => 100,
+            Upgrade::SlowerBaseSpeed => 80,
+            Upgrade::LongerPowerUps => 60,
+            Upgrade::HigherEffectChance => 60,
+        }
+    }
+
+    pub fn get_label(&self) -> String {
+        let name = match self {
+            Upgrade::ExtraLife => "EXTRA LIFE",
+            Upgrade::SlowerBaseSpeed => "SLOWER SPEED",
+            Upgrade::LongerPowerUps => "LONGER POWER-UPS",
+            Upgrade::HigherEffectChance => "MORE POWER-UPS",
+        };
+        format!("{} ({})", name, self.cost())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WaveState {
+    pub wave_number: u32,
+    pub words_left_to_spawn: u32,
+    pub intermission_time_left: Option<f32>,
+}
+
+impl Default for WaveState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WaveState {
+    pub fn new() -> Self {
+        Self {
+            wave_number: 1,
+            words_left_to_spawn: WORDS_PER_WAVE,
+            intermission_time_left: None,
+        }
+    }
+
+    pub fn is_in_intermission(&self) -> bool {
+        self.intermission_time_left.is_some()
+    }
+
+    pub fn can_spawn_word(&self) -> bool {
+        !self.is_in_intermission() && self.words_left_to_spawn > 0
+    }
+
+    pub fn register_spawned_word(&mut self) {
+        if self.words_left_to_spawn > 0 {
+            self.words_left_to_spawn -= 1;
+        }
+    }
+
+    pub fn is_cleared(&self, words_on_screen: usize) -> bool {
+        !self.is_in_intermission() && self.words_left_to_spawn == 0 && words_on_screen == 0
+    }
+
+    pub fn start_intermission(&mut self) {
+        self.intermission_time_left = Some(INTERMISSION_LENGTH);
+    }
+
+    // returns true once the intermission is over and the next wave should begin
+    pub fn update_intermission(&mut self, last_frame_length: f32) -> bool {
+        match self.intermission_time_left {
+            Some(time_left) if time_left - last_frame_length > 0.0 => {
+                self.intermission_time_left = Some(time_left - last_frame_length);
+                false
+            }
+            Some(_) => true,
+            None => false,
+        }
+    }
+
+    pub fn start_next_wave(&mut self) {
+        self.wave_number += 1;
+        self.words_left_to_spawn = WORDS_PER_WAVE;
+        self.intermission_time_left = None;
+    }
+}
@@ -1,44 +1,179 @@
-use ggez::{graphics::Color, mint::Point2};
-use rand::{seq::SliceRandom, Rng};
+use ggez::mint::Point2;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::effects::get_default_effect_table;
+use crate::motion::Motion;
+use crate::preset::Difficulty;
+
+pub const DEFAULT_EFFECT_CHANCE: u32 = 20;
+pub const MASK_CHARACTER: char = '*';
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum WordVisibility {
+    Visible,
+    Revealed { time_left: f32 },
+    Masked,
+}
+
 pub struct Word {
     pub value: String,
     pub position: Point2<f32>,
     pub progress_index: usize,
     pub effect: Option<WordEffect>,
+    pub visibility: WordVisibility,
+    pub mistakes: u32,
+    pub motion: Motion,
+    // seconds since the word spawned
+    pub age: f32,
 }
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum WordEffect {
     SlowDown,
     AddLife,
     SpawnOnlyShortWords,
+    Freeze,
+    Bomb,
+    DoubleScore,
+    Shield,
+    SpeedBurst,
+    Fog,
+    Scramble,
+    Mirror,
+    Split,
+}
+
+// when a hazard word does its damage
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HazardTrigger {
+    Completed,
+    Missed,
+}
+
+impl WordEffect {
+    pub const ALL: [WordEffect; 12] = [
+        WordEffect::SlowDown,
+        WordEffect::AddLife,
+        WordEffect::SpawnOnlyShortWords,
+        WordEffect::Freeze,
+        WordEffect::Bomb,
+        WordEffect::DoubleScore,
+        WordEffect::Shield,
+        WordEffect::SpeedBurst,
+        WordEffect::Fog,
+        WordEffect::Scramble,
+        WordEffect::Mirror,
+        WordEffect::Split,
+    ];
+
+    pub const POWER_UPS: [WordEffect; 7] = [
+        WordEffect::SlowDown,
+        WordEffect::AddLife,
+        WordEffect::SpawnOnlyShortWords,
+        WordEffect::Freeze,
+        WordEffect::Bomb,
+        WordEffect::DoubleScore,
+        WordEffect::Shield,
+    ];
+
+    pub const HAZARDS: [WordEffect; 5] = [
+        WordEffect::SpeedBurst,
+        WordEffect::Fog,
+        WordEffect::Scramble,
+        WordEffect::Mirror,
+        WordEffect::Split,
+    ];
+
+    pub fn get_label(&self) -> String {
+        match self {
+            WordEffect::SlowDown => String::from("SLOW DOWN"),
+            WordEffect::AddLife => String::from("EXTRA LIFE"),
+            WordEffect::SpawnOnlyShortWords => String::from("SHORT WORDS"),
+            WordEffect::Freeze => String::from("FREEZE"),
+            WordEffect::Bomb => String::from("BOMB"),
+            WordEffect::DoubleScore => String::from("DOUBLE SCORE"),
+            WordEffect::Shield => String::from("SHIELD"),
+            WordEffect::SpeedBurst => String::from("SPEED BURST"),
+            WordEffect::Fog => String::from("FOG"),
+            WordEffect::Scramble => String::from("SCRAMBLE"),
+            WordEffect::Mirror => String::from("MIRROR"),
+            WordEffect::Split => String::from("SPLIT"),
+        }
+    }
+
+    pub fn is_hazard(&self) -> bool {
+        self.get_hazard_trigger().is_some()
+    }
+
+    pub fn get_hazard_trigger(&self) -> Option<HazardTrigger> {
+        match self {
+            WordEffect::SpeedBurst | WordEffect::Scramble | WordEffect::Split => {
+                Some(HazardTrigger::Completed)
+            }
+            WordEffect::Fog | WordEffect::Mirror => Some(HazardTrigger::Missed),
+            _ => None,
+        }
+    }
 }
 
 impl Word {
     pub fn new(value: &str, position: Point2<f32>, progress_index: usize) -> Self {
-        let mut rng = rand::thread_rng();
-        let rand_number = rng.gen_range(1..=100);
-
-        let word_effect;
-        if rand_number > 80 {
-            word_effect = Some(
-                *vec![
-                    WordEffect::SlowDown,
-                    WordEffect::AddLife,
-                    WordEffect::SpawnOnlyShortWords,
-                ]
-                .choose(&mut rand::thread_rng())
-                .unwrap(),
-            )
-        } else {
-            word_effect = None
-        }
+        Self::with_rng(value, position, progress_index, &mut rand::thread_rng())
+    }
+
+    // rolls the effect with the rng passed in, so a seeded game spawns the same ones
+    pub fn with_rng<R: Rng + ?Sized>(
+        value: &str,
+        position: Point2<f32>,
+        progress_index: usize,
+        rng: &mut R,
+    ) -> Self {
+        Self::new_with_effect_chance(
+            value,
+            position,
+            progress_index,
+            DEFAULT_EFFECT_CHANCE,
+            &WordEffect::POWER_UPS,
+            rng,
+        )
+    }
+
+    pub fn new_with_effect_chance<R: Rng + ?Sized>(
+        value: &str,
+        position: Point2<f32>,
+        progress_index: usize,
+        effect_chance: u32,
+        effects: &[WordEffect],
+        rng: &mut R,
+    ) -> Self {
+        let word_effect = get_default_effect_table().roll_effect(
+            effect_chance,
+            effects,
+            0,
+            Difficulty::Normal,
+            rng,
+        );
+        Self::new_with_effect(value, position, progress_index, word_effect)
+    }
+
+    pub fn new_with_effect(
+        value: &str,
+        position: Point2<f32>,
+        progress_index: usize,
+        effect: Option<WordEffect>,
+    ) -> Self {
         Self {
             value: String::from(value),
             position,
             progress_index,
-            effect: word_effect,
+            effect,
+            visibility: WordVisibility::Visible,
+            mistakes: 0,
+            motion: Motion::default(),
+            age: 0.0,
         }
     }
 
@@ -49,17 +184,67 @@ impl Word {
             .collect()
     }
 
+    pub fn get_masked_value(&self, hide_typed_letters: bool) -> String {
+        let graphemes_count = self.value.graphemes(true).count();
+        if hide_typed_letters {
+            return MASK_CHARACTER.to_string().repeat(graphemes_count);
+        }
+        let typed_letters: String = self
+            .value
+            .graphemes(true)
+            .take(self.progress_index)
+            .collect();
+        typed_letters
+            + &MASK_CHARACTER
+                .to_string()
+                .repeat(graphemes_count - self.progress_index)
+    }
+
+    pub fn is_masked(&self) -> bool {
+        self.visibility == WordVisibility::Masked
+    }
+
+    pub fn update_visibility(&mut self, last_frame_length: f32) {
+        if let WordVisibility::Revealed { time_left } = self.visibility {
+            self.visibility = if time_left - last_frame_length <= 0.0 {
+                WordVisibility::Masked
+            } else {
+                WordVisibility::Revealed {
+                    time_left: time_left - last_frame_length,
+                }
+            };
+        }
+    }
+
+    pub fn get_accuracy(&self) -> f32 {
+        let letters_count = self.value.graphemes(true).count() as f32;
+        letters_count / (letters_count + self.mistakes as f32)
+    }
+
     pub fn update_position(&mut self, delta_y: f32) {
         self.position.y += delta_y
     }
 
-    pub fn get_color(&self) -> Color {
-        match self.effect {
-            Some(WordEffect::SlowDown) => Color::new(0.06, 0.9, 0.92, 0.65),
-            Some(WordEffect::AddLife) => Color::GREEN,
-            Some(WordEffect::SpawnOnlyShortWords) => Color::YELLOW,
-            None => Color::WHITE,
+    pub fn update_motion(&mut self, fall_distance: f32, last_frame_length: f32, max_x: f32) {
+        self.motion
+            .update(&mut self.position, fall_distance, last_frame_length, max_x)
+    }
+
+    // shuffles the letters that are still left to type
+    pub fn scramble<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut graphemes: Vec<&str> = self.value.graphemes(true).collect();
+        graphemes[self.progress_index..].shuffle(rng);
+        self.value = graphemes.concat();
+    }
+
+    // the two halves of the word, None if it is too short to split
+    pub fn get_split_values(&self) -> Option<(String, String)> {
+        let graphemes: Vec<&str> = self.value.graphemes(true).collect();
+        if graphemes.len() < 2 {
+            return None;
         }
+        let (first, second) = graphemes.split_at(graphemes.len() / 2);
+        Some((first.concat(), second.concat()))
     }
 
     pub fn handle_typed_letter(&mut self, letter: char) {
@@ -67,9 +252,12 @@ impl Word {
 
         if current_letter == letter {
             self.progress_index += 1;
+        } else {
+            self.mistakes += 1;
         }
     }
 
+    #[allow(clippy::needless_return)]
     pub fn is_completed(&self) -> bool {
         return self.progress_index == self.value.graphemes(true).count();
     }
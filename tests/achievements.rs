@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::achievements::{
+        Achievement, Achievements, RunStats, BURST_WORD_COUNT, DAYS_IN_A_ROW, HIGH_SCORE,
+    };
+
+    #[test]
+    fn it_measures_the_burst_speed_over_the_latest_words() {
+        let mut stats = RunStats::default();
+        for _ in 0..BURST_WORD_COUNT {
+            stats.register_completed_word(5);
+            stats.elapsed_time += 0.5;
+        }
+
+        // four five letter words in two seconds
+        assert!((stats.get_burst_wpm() - 120.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn it_unlocks_each_achievement_only_once() {
+        let mut achievements = Achievements::default();
+        let stats = RunStats {
+            score: HIGH_SCORE,
+            ..RunStats::default()
+        };
+
+        assert_eq!(achievements.check(&stats), vec![Achievement::HighScore]);
+        assert!(achievements.is_unlocked(Achievement::HighScore));
+        assert!(achievements.check(&stats).is_empty());
+    }
+
+    #[test]
+    fn it_requires_a_mistake_free_run_for_the_flawless_achievement() {
+        let mut achievements = Achievements::default();
+        let mut stats = RunStats::default();
+        for _ in 0..50 {
+            stats.register_completed_word(3);
+            stats.elapsed_time += 10.0;
+        }
+        stats.mistakes = 1;
+        assert!(achievements.check(&stats).is_empty());
+
+        stats.mistakes = 0;
+        assert_eq!(achievements.check(&stats), vec![Achievement::FlawlessGame]);
+    }
+
+    #[test]
+    fn it_counts_the_days_played_in_a_row() {
+        let mut achievements = Achievements::default();
+        for day in 100..100 + DAYS_IN_A_ROW as u64 - 1 {
+            achievements.register_play_day(day);
+            achievements.register_play_day(day);
+        }
+        assert!(achievements.check(&RunStats::default()).is_empty());
+
+        achievements.register_play_day(100 + DAYS_IN_A_ROW as u64 - 1);
+        assert_eq!(
+            achievements.check(&RunStats::default()),
+            vec![Achievement::DailyPlayer]
+        );
+
+        achievements.register_play_day(200);
+        assert_eq!(achievements.days_in_a_row, 1);
+    }
+}
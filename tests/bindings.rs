@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use ggez::input::keyboard::KeyCode;
+    use typing_tutor::bindings::{get_key_by_name, Action, BindingError, Bindings};
+    use typing_tutor::settings::Settings;
+
+    #[test]
+    fn it_binds_enter_and_escape_by_default() {
+        let bindings = Bindings::default();
+
+        assert_eq!(
+            bindings.get_menu_action(KeyCode::Return),
+            Some(Action::Select)
+        );
+        assert_eq!(
+            bindings.get_menu_action(KeyCode::Escape),
+            Some(Action::Back)
+        );
+        assert_eq!(
+            bindings.get_game_action(KeyCode::Escape),
+            Some(Action::Pause)
+        );
+        assert_eq!(bindings.get_menu_action(KeyCode::A), None);
+    }
+
+    #[test]
+    fn it_detects_conflicts_within_the_same_context() {
+        let mut bindings = Bindings::default();
+
+        assert_eq!(
+            bindings.bind(Action::Select, KeyCode::Left),
+            Err(BindingError::Conflict(Action::Back))
+        );
+        assert_eq!(
+            bindings.bind(Action::Pause, KeyCode::P),
+            Err(BindingError::TypingKey)
+        );
+        assert_eq!(bindings.bind(Action::Pause, KeyCode::Tab), Ok(()));
+        assert_eq!(bindings.get_keys(Action::Pause), &[KeyCode::Tab]);
+        // the pause key is only read during the game, so menus may share it
+        assert_eq!(bindings.bind(Action::Details, KeyCode::Tab), Ok(()));
+    }
+
+    #[test]
+    fn it_rejects_keys_that_cannot_be_saved() {
+        let mut bindings = Bindings::default();
+
+        assert_eq!(
+            bindings.bind(Action::Select, KeyCode::Numpad5),
+            Err(BindingError::Unsupported)
+        );
+        assert_eq!(get_key_by_name("Return"), Some(KeyCode::Return));
+        assert_eq!(get_key_by_name("Numpad5"), None);
+    }
+
+    #[test]
+    fn it_labels_every_action_with_its_keys() {
+        assert_eq!(
+            Bindings::default().get_option_label(Action::Select),
+            "SELECT: SPACE, RETURN"
+        );
+    }
+
+    #[test]
+    fn it_saves_the_bindings_with_the_settings() {
+        let mut settings = Settings::default();
+        settings
+            .bindings
+            .bind(Action::Select, KeyCode::Tab)
+            .unwrap();
+
+        let contents = toml::to_string(&settings).unwrap();
+        let loaded = Settings::from_toml_str(&contents).unwrap();
+
+        assert_eq!(loaded.bindings, settings.bindings);
+    }
+
+    #[test]
+    fn it_keeps_the_defaults_for_actions_missing_from_the_file() {
+        let settings = Settings::from_toml_str(
+            r#"
+            [bindings]
+            pause = ["F1"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.bindings.get_keys(Action::Pause), &[KeyCode::F1]);
+        assert_eq!(
+            settings.bindings.get_keys(Action::Back),
+            &[KeyCode::Left, KeyCode::Escape]
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_defaults_for_actions_with_unknown_keys() {
+        let settings = Settings::from_toml_str(
+            r#"
+            theme = "green"
+
+            [bindings]
+            pause = ["Numpad5"]
+            select = ["Tab"]
+            teleport = ["F2"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.theme, "green");
+        assert_eq!(
+            settings.bindings.get_keys(Action::Pause),
+            &[KeyCode::Escape]
+        );
+        assert_eq!(settings.bindings.get_keys(Action::Select), &[KeyCode::Tab]);
+    }
+
+    #[test]
+    fn it_resets_actions_that_break_the_binding_rules_on_load() {
+        let settings = Settings::from_toml_str(
+            r#"
+            [bindings]
+            menu_up = ["Up"]
+            menu_down = ["Up"]
+            select = []
+            pause = ["P"]
+            details = ["Tab"]
+            "#,
+        )
+        .unwrap();
+        let bindings = &settings.bindings;
+
+        assert_eq!(bindings.get_keys(Action::MenuUp), &[KeyCode::Up]);
+        assert_eq!(bindings.get_keys(Action::MenuDown), &[KeyCode::Down]);
+        assert_eq!(
+            bindings.get_keys(Action::Select),
+            &[KeyCode::Space, KeyCode::Return]
+        );
+        assert_eq!(bindings.get_keys(Action::Pause), &[KeyCode::Escape]);
+        assert_eq!(bindings.get_keys(Action::Details), &[KeyCode::Tab]);
+        assert!(Action::ALL.iter().all(|action| bindings.is_valid(*action)));
+    }
+}
@@ -0,0 +1,182 @@
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path};
+
+    use typing_tutor::campaign::{Campaign, CampaignProgress, Level, WinCondition};
+    use typing_tutor::effects::EffectTable;
+    use typing_tutor::motion::MotionPattern;
+    use typing_tutor::rules::{MAX_LIVES, STARTING_LIVES};
+    use typing_tutor::word::WordEffect;
+
+    const LEVEL: &str = r#"
+        name = "Test level"
+        words = ["cat", "dog"]
+        allowed_effects = ["slow_down"]
+        motion_patterns = ["linear", "sine_drift"]
+        effect_durations = { slow_down = 3.0 }
+        stars = [100, 200, 300]
+
+        [speed]
+        initial = 40
+        increase_per_word = 2
+        max = 60
+
+        [spawn]
+        initial_interval = 1.5
+        decay = 0.01
+        min_interval = 0.5
+
+        [win]
+        complete_words = 30
+    "#;
+
+    fn create_level(name: &str) -> Level {
+        let mut level = Level::from_toml_str(LEVEL).unwrap();
+        level.name = name.to_string();
+        level
+    }
+
+    #[test]
+    fn it_parses_a_level_file() {
+        let level = Level::from_toml_str(LEVEL).unwrap();
+
+        assert_eq!(level.name, "Test level");
+        assert_eq!(level.words, ["cat", "dog"]);
+        assert_eq!(level.allowed_effects, [WordEffect::SlowDown]);
+        assert_eq!(
+            level.motion_patterns,
+            [MotionPattern::Linear, MotionPattern::SineDrift]
+        );
+        assert_eq!(
+            level.effect_durations.get(&WordEffect::SlowDown),
+            Some(&3.0)
+        );
+        assert_eq!(level.win, WinCondition::CompleteWords(30));
+
+        let rules = level.get_rules();
+        assert_eq!(rules.initial_game_speed, 40);
+        assert_eq!(rules.speed_increase_per_word, 2);
+        assert_eq!(rules.max_game_speed, Some(60));
+        assert_eq!(rules.initial_spawn_interval, 1.5);
+        assert_eq!(rules.spawn_interval_decay, 0.01);
+        assert_eq!(rules.min_spawn_interval, 0.5);
+        assert_eq!(rules.word_score, 10);
+        assert_eq!(rules.starting_lives, STARTING_LIVES);
+        assert_eq!(rules.max_lives, MAX_LIVES);
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_level_file() {
+        assert!(Level::from_toml_str("name = \"no curves\"").is_err());
+    }
+
+    #[test]
+    fn it_parses_all_bundled_levels() {
+        let mut levels_dir = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        levels_dir.push("resources");
+        levels_dir.push("levels");
+
+        let entries = fs::read_dir(levels_dir).unwrap();
+        for entry in entries {
+            let contents = fs::read_to_string(entry.unwrap().path()).unwrap();
+            assert!(Level::from_toml_str(&contents).is_ok());
+        }
+    }
+
+    #[test]
+    fn it_sets_reachable_star_thresholds_in_all_bundled_levels() {
+        let mut levels_dir = path::PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        levels_dir.push("resources");
+        levels_dir.push("levels");
+
+        let entries = fs::read_dir(levels_dir).unwrap();
+        for entry in entries {
+            let contents = fs::read_to_string(entry.unwrap().path()).unwrap();
+            let level = Level::from_toml_str(&contents).unwrap();
+            for threshold in level.stars {
+                assert!(
+                    threshold <= level.get_max_score(&EffectTable::default()),
+                    "{} can't reach {} points",
+                    level.name,
+                    threshold
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_limits_the_score_of_a_score_goal_to_one_more_word() {
+        let mut level = create_level("Score goal");
+        level.win = WinCondition::ReachScore(300);
+
+        let effect_table = EffectTable::default();
+        let max_word_score = level.get_max_word_score(&effect_table);
+        assert!(max_word_score > 0);
+        assert_eq!(level.get_max_score(&effect_table), 299 + max_word_score);
+        assert!(level.get_max_score(&effect_table) < 400);
+    }
+
+    #[test]
+    fn it_takes_the_double_score_multiplier_from_the_effect_table() {
+        let mut level = create_level("Double score");
+        level.allowed_effects = vec![WordEffect::DoubleScore];
+        let mut effect_table = EffectTable::default();
+        let max_word_score = level.get_max_word_score(&effect_table);
+
+        for definition in &mut effect_table.definitions {
+            if definition.effect == WordEffect::DoubleScore {
+                definition.value = Some(4);
+            }
+        }
+        assert_eq!(level.get_max_word_score(&effect_table), max_word_score * 2);
+    }
+
+    #[test]
+    fn it_checks_win_conditions() {
+        assert!(!WinCondition::CompleteWords(30).is_met(29, 1000));
+        assert!(WinCondition::CompleteWords(30).is_met(30, 0));
+        assert!(!WinCondition::ReachScore(300).is_met(100, 290));
+        assert!(WinCondition::ReachScore(300).is_met(0, 300));
+    }
+
+    #[test]
+    fn it_counts_stars_by_score_thresholds() {
+        let level = create_level("level");
+
+        assert_eq!(level.count_stars(50), 0);
+        assert_eq!(level.count_stars(100), 1);
+        assert_eq!(level.count_stars(250), 2);
+        assert_eq!(level.count_stars(300), 3);
+    }
+
+    #[test]
+    fn it_unlocks_the_next_level_on_completion() {
+        let mut progress = CampaignProgress::default();
+        assert_eq!(progress.unlocked_levels, 1);
+
+        progress.record_level_completion(0, 2);
+        assert_eq!(progress.unlocked_levels, 2);
+        assert_eq!(progress.get_stars(0), 2);
+
+        // replaying a level keeps the best result
+        progress.record_level_completion(0, 1);
+        assert_eq!(progress.unlocked_levels, 2);
+        assert_eq!(progress.get_stars(0), 2);
+    }
+
+    #[test]
+    fn it_labels_locked_and_unlocked_levels() {
+        let mut campaign =
+            Campaign::new(Vec::from([create_level("first"), create_level("second")]));
+
+        assert!(campaign.is_unlocked(0));
+        assert!(!campaign.is_unlocked(1));
+        assert_eq!(campaign.get_level_label(0), "1. FIRST (0/3)");
+        assert_eq!(campaign.get_level_label(1), "2. LOCKED");
+
+        campaign.progress.record_level_completion(0, 3);
+        assert!(campaign.is_unlocked(1));
+        assert!(!campaign.is_unlocked(2));
+        assert_eq!(campaign.get_level_label(0), "1. FIRST (3/3)");
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use clap::error::ErrorKind;
+    use clap::Parser;
+    use typing_tutor::cli::{parse_word_list, Cli};
+    use typing_tutor::game::GameMode;
+    use typing_tutor::preset::Difficulty;
+
+    #[test]
+    fn it_parses_every_option() {
+        let cli = Cli::try_parse_from([
+            "typing-tutor",
+            "--mode",
+            "waves",
+            "--words",
+            "words.txt",
+            "--seed",
+            "42",
+            "--difficulty",
+            "Hard",
+            "--fullscreen",
+            "--window-size",
+            "1280x720",
+            "--config",
+            "settings.toml",
+            "--skip-menu",
+            "--spectator",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.mode, Some(GameMode::Waves));
+        assert_eq!(cli.words, Some(PathBuf::from("words.txt")));
+        assert_eq!(cli.seed, Some(42));
+        assert_eq!(cli.difficulty, Some(Difficulty::Hard));
+        assert!(cli.fullscreen);
+        assert_eq!(cli.window_size, Some((1280.0, 720.0)));
+        assert_eq!(cli.config, Some(PathBuf::from("settings.toml")));
+        assert!(cli.skip_menu);
+        assert!(cli.spectator);
+    }
+
+    #[test]
+    fn it_leaves_everything_unset_without_arguments() {
+        let cli = Cli::try_parse_from(["typing-tutor"]).unwrap();
+
+        assert_eq!(cli.mode, None);
+        assert_eq!(cli.seed, None);
+        assert_eq!(cli.window_size, None);
+        assert!(!cli.fullscreen);
+        assert!(!cli.skip_menu);
+        assert!(!cli.spectator);
+    }
+
+    #[test]
+    fn it_rejects_invalid_values() {
+        for args in [
+            ["typing-tutor", "--mode", "fast"],
+            ["typing-tutor", "--difficulty", "nightmare"],
+            ["typing-tutor", "--seed", "many"],
+            ["typing-tutor", "--window-size", "1280"],
+            ["typing-tutor", "--window-size", "320x240"],
+        ] {
+            let err = Cli::try_parse_from(args).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::ValueValidation, "{:?}", args);
+        }
+    }
+
+    #[test]
+    fn it_rejects_unknown_arguments() {
+        let err = Cli::try_parse_from(["typing-tutor", "--turbo"]).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    }
+
+    #[test]
+    fn it_keeps_only_typeable_words_from_a_word_list() {
+        let words = parse_word_list("Rust\n\n  crab \ndon't\nnaïve\nferris2\nborrow\n").unwrap();
+
+        assert_eq!(words, vec!["rust", "crab", "borrow"]);
+    }
+
+    #[test]
+    fn it_rejects_a_word_list_without_typeable_words() {
+        assert!(parse_word_list("").is_err());
+        assert!(parse_word_list("123\nhello world\n").is_err());
+    }
+}
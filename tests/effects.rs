@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod tests {
+    use ggez::graphics::Color;
+    use typing_tutor::effects::{ActiveEffects, EffectConfig, EffectTable, StackingRule};
+    use typing_tutor::preset::Difficulty;
+    use typing_tutor::word::WordEffect;
+
+    fn get_default_duration(effect: WordEffect) -> f32 {
+        ActiveEffects::default().get_duration(effect)
+    }
+
+    fn get_time_left(effects: &ActiveEffects, effect: WordEffect) -> Option<f32> {
+        effects.get(effect).map(|active| active.time_left)
+    }
+
+    #[test]
+    fn it_does_not_keep_instant_effects_active() {
+        let mut effects = ActiveEffects::default();
+
+        effects.apply(WordEffect::AddLife);
+
+        assert!(!effects.is_active(WordEffect::AddLife));
+        assert_eq!(effects.iter().count(), 0);
+    }
+
+    #[test]
+    fn it_expires_effects_once_their_time_runs_out() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(WordEffect::SlowDown);
+
+        let duration = get_default_duration(WordEffect::SlowDown);
+        assert!(effects.tick(duration - 1.0).is_empty());
+        assert!(effects.is_active(WordEffect::SlowDown));
+
+        assert_eq!(effects.tick(1.0), [WordEffect::SlowDown]);
+        assert!(!effects.is_active(WordEffect::SlowDown));
+    }
+
+    #[test]
+    fn it_refreshes_an_overlapping_slow_down() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(WordEffect::SlowDown);
+        effects.tick(3.0);
+
+        effects.apply(WordEffect::SlowDown);
+
+        assert_eq!(
+            get_time_left(&effects, WordEffect::SlowDown),
+            Some(get_default_duration(WordEffect::SlowDown))
+        );
+        assert_eq!(effects.iter().count(), 1);
+    }
+
+    #[test]
+    fn it_extends_overlapping_short_words() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(WordEffect::SpawnOnlyShortWords);
+        effects.tick(3.0);
+
+        effects.apply(WordEffect::SpawnOnlyShortWords);
+
+        assert_eq!(
+            get_time_left(&effects, WordEffect::SpawnOnlyShortWords),
+            Some(get_default_duration(WordEffect::SpawnOnlyShortWords) * 2.0 - 3.0)
+        );
+    }
+
+    #[test]
+    fn it_stacks_effects_configured_to_stack() {
+        let mut effects = ActiveEffects::default();
+        effects.set_config(
+            WordEffect::SlowDown,
+            EffectConfig {
+                duration: 2.0,
+                stacking: StackingRule::Stack,
+            },
+        );
+
+        effects.apply(WordEffect::SlowDown);
+        effects.tick(1.0);
+        effects.apply(WordEffect::SlowDown);
+
+        let active = effects.get(WordEffect::SlowDown).unwrap();
+        assert_eq!(active.stacks, 2);
+        assert_eq!(active.time_left, 2.0);
+    }
+
+    #[test]
+    fn it_ticks_different_effects_independently() {
+        let mut effects = ActiveEffects::default();
+        effects.set_duration(WordEffect::SlowDown, 1.0);
+        effects.apply(WordEffect::SlowDown);
+        effects.apply(WordEffect::SpawnOnlyShortWords);
+
+        assert_eq!(effects.tick(1.5), [WordEffect::SlowDown]);
+        assert!(effects.is_active(WordEffect::SpawnOnlyShortWords));
+    }
+
+    #[test]
+    fn it_adds_the_bonus_duration_to_timed_effects_only() {
+        let mut effects = ActiveEffects::default();
+        let duration = effects.get_duration(WordEffect::SlowDown);
+        effects.add_bonus_duration(2.0);
+
+        assert_eq!(effects.get_duration(WordEffect::SlowDown), duration + 2.0);
+        assert_eq!(effects.get_duration(WordEffect::AddLife), 0.0);
+
+        effects.reset();
+        assert_eq!(effects.get_duration(WordEffect::SlowDown), duration);
+    }
+
+    #[test]
+    fn it_keeps_shield_charges_until_they_are_consumed() {
+        let mut effects = ActiveEffects::default();
+        effects.apply(WordEffect::Shield);
+        effects.apply(WordEffect::Shield);
+
+        assert!(effects.tick(1000.0).is_empty());
+        assert!(effects.consume(WordEffect::Shield));
+        assert!(effects.consume(WordEffect::Shield));
+        assert!(!effects.consume(WordEffect::Shield));
+    }
+
+    #[test]
+    fn it_defines_every_effect_in_the_bundled_table() {
+        let table = EffectTable::default();
+
+        for effect in WordEffect::ALL {
+            assert!(table.get(effect).is_some());
+        }
+        assert_eq!(table.get_value(WordEffect::SlowDown, 0), 25);
+        assert_eq!(table.get_color(Some(WordEffect::AddLife)), Color::GREEN);
+        assert_eq!(table.get_color(None), Color::WHITE);
+        assert_eq!(table.get_font(None, "GravitasOne"), "GravitasOne");
+    }
+
+    #[test]
+    fn it_returns_the_color_of_each_effect() {
+        let table = EffectTable::default();
+
+        assert_eq!(
+            table.get_color(Some(WordEffect::SlowDown)),
+            Color::new(0.06, 0.9, 0.92, 0.65)
+        );
+        assert_eq!(
+            table.get_color(Some(WordEffect::SpawnOnlyShortWords)),
+            Color::YELLOW
+        );
+        assert_eq!(
+            table.get_color(Some(WordEffect::Freeze)),
+            Color::new(0.75, 0.9, 1.0, 1.0)
+        );
+        assert_eq!(table.get_color(Some(WordEffect::Bomb)), Color::RED);
+        assert_eq!(
+            table.get_color(Some(WordEffect::DoubleScore)),
+            Color::MAGENTA
+        );
+        assert_eq!(
+            table.get_color(Some(WordEffect::Shield)),
+            Color::new(1.0, 0.6, 0.1, 1.0)
+        );
+    }
+
+    #[test]
+    fn it_returns_the_font_depending_on_the_effect() {
+        let table = EffectTable::default();
+
+        assert_eq!(
+            table.get_font(Some(WordEffect::SlowDown), "GravitasOne"),
+            "BungeeShade"
+        );
+        assert_eq!(
+            table.get_font(Some(WordEffect::Bomb), "GravitasOne"),
+            "Creepster"
+        );
+    }
+
+    #[test]
+    fn it_tells_power_ups_with_the_same_font_apart_by_their_color() {
+        let table = EffectTable::default();
+        let effects = [
+            WordEffect::Freeze,
+            WordEffect::DoubleScore,
+            WordEffect::Shield,
+        ];
+        let mut colors = Vec::new();
+        for effect in effects {
+            assert_eq!(table.get_font(Some(effect), "GravitasOne"), "BungeeShade");
+            colors.push(table.get_color(Some(effect)));
+        }
+
+        assert_ne!(colors[0], colors[1]);
+        assert_ne!(colors[0], colors[2]);
+        assert_ne!(colors[1], colors[2]);
+    }
+
+    #[test]
+    fn it_rejects_a_table_with_a_missing_definition() {
+        let contents = r#"
+            [[effect]]
+            effect = "slow_down"
+            weight = 1
+            color = [1.0, 1.0, 1.0, 1.0]
+            font = "BungeeShade"
+            duration = 5.0
+            stacking = "refresh"
+        "#;
+
+        assert!(EffectTable::from_toml_str(contents).is_err());
+    }
+
+    #[test]
+    fn it_rolls_only_effects_unlocked_by_the_score() {
+        let table = EffectTable::default();
+        let min_score = table.get(WordEffect::Bomb).unwrap().min_score;
+
+        assert!(table
+            .roll_effect(
+                100,
+                &[WordEffect::Bomb],
+                0,
+                Difficulty::Normal,
+                &mut rand::thread_rng()
+            )
+            .is_none());
+        assert_eq!(
+            table.roll_effect(
+                100,
+                &[WordEffect::Bomb],
+                min_score,
+                Difficulty::Normal,
+                &mut rand::thread_rng()
+            ),
+            Some(WordEffect::Bomb)
+        );
+        assert!(table
+            .roll_effect(
+                0,
+                &[WordEffect::Bomb],
+                min_score,
+                Difficulty::Normal,
+                &mut rand::thread_rng()
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn it_overrides_weights_per_difficulty() {
+        let mut table = EffectTable::default();
+        let add_life = table
+            .definitions
+            .iter_mut()
+            .find(|definition| definition.effect == WordEffect::AddLife)
+            .unwrap();
+        add_life.weight = 3;
+        add_life.difficulty_weights.insert(Difficulty::Insane, 0);
+
+        assert_eq!(add_life.get_weight(Difficulty::Normal), 3);
+        assert_eq!(add_life.get_weight(Difficulty::Insane), 0);
+        assert!(table
+            .roll_effect(
+                100,
+                &[WordEffect::AddLife],
+                0,
+                Difficulty::Insane,
+                &mut rand::thread_rng()
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn it_drains_the_remaining_fraction_of_timed_effects_only() {
+        let mut effects = ActiveEffects::default();
+        effects.set_duration(WordEffect::SlowDown, 4.0);
+        effects.apply(WordEffect::SlowDown);
+        effects.apply(WordEffect::Shield);
+
+        effects.tick(1.0);
+
+        let slow_down = effects.get(WordEffect::SlowDown).unwrap();
+        assert_eq!(slow_down.get_remaining_fraction(), 0.75);
+        let shield = effects.get(WordEffect::Shield).unwrap();
+        assert_eq!(shield.get_remaining_fraction(), 1.0);
+    }
+}
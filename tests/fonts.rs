@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use typing_tutor::fonts::{
+        get_font_name, get_user_fonts_dir, is_font_file, resolve_font, FALLBACK_FONT, UI_FONT,
+    };
+
+    #[test]
+    fn it_only_picks_up_truetype_and_opentype_files() {
+        assert!(is_font_file(Path::new("/GravitasOne.ttf")));
+        assert!(is_font_file(Path::new("fonts/Mono.OTF")));
+        assert!(!is_font_file(Path::new("/effects.toml")));
+        assert!(!is_font_file(Path::new("/levels")));
+    }
+
+    #[test]
+    fn it_names_fonts_after_their_file() {
+        assert_eq!(
+            get_font_name(Path::new("/home/fonts/Fira Code.ttf")),
+            Some(String::from("Fira Code"))
+        );
+    }
+
+    #[test]
+    fn it_keeps_user_fonts_in_the_data_directory() {
+        assert_eq!(
+            get_user_fonts_dir(Path::new("/data")),
+            Path::new("/data/fonts")
+        );
+    }
+
+    #[test]
+    fn it_falls_back_to_the_ggez_font_when_a_font_was_not_loaded() {
+        let fonts = vec![String::from("GravitasOne"), String::from(UI_FONT)];
+
+        assert_eq!(resolve_font(&fonts, UI_FONT), UI_FONT);
+        assert_eq!(resolve_font(&fonts, "Creepster"), FALLBACK_FONT);
+        assert_eq!(resolve_font(&[], UI_FONT), FALLBACK_FONT);
+    }
+}
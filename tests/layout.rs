@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use ggez::conf::FullscreenType;
+    use typing_tutor::layout::{
+        get_nearest_option, get_next_option, DisplayMode, Layout, UI_SCALES, WORD_SIZES,
+    };
+
+    #[test]
+    fn it_keeps_reference_sizes_on_the_reference_window() {
+        let layout = Layout::default();
+
+        assert_eq!(layout.ui(50.0), 50.0);
+        assert_eq!(layout.get_word_size(), 40.0);
+        assert_eq!(layout.from_bottom(100.0), 900.0);
+        assert_eq!(layout.get_menu_option_y(1), 270.0);
+    }
+
+    #[test]
+    fn it_scales_with_the_tighter_side_of_the_window() {
+        let small = Layout::new(1366.0, 500.0, 100, 40);
+        let large = Layout::new(3840.0, 2160.0, 100, 40);
+
+        assert_eq!(small.ui(50.0), 25.0);
+        assert_eq!(small.from_bottom(100.0), 450.0);
+        assert!((large.ui(50.0) - 108.0).abs() < 0.001);
+        assert_eq!(large.center_x(840.0), 1500.0);
+    }
+
+    #[test]
+    fn it_applies_the_ui_scale_and_word_size_separately() {
+        let layout = Layout::new(1200.0, 1000.0, 150, 60);
+
+        assert_eq!(layout.ui(40.0), 60.0);
+        assert_eq!(layout.get_word_size(), 60.0);
+        assert_eq!(layout.word(200.0), 300.0);
+    }
+
+    #[test]
+    fn it_cycles_the_setting_values() {
+        assert_eq!(get_next_option(&UI_SCALES, 100), 125);
+        assert_eq!(get_next_option(&UI_SCALES, 200), 50);
+        assert_eq!(get_next_option(&WORD_SIZES, 33), WORD_SIZES[0]);
+    }
+
+    #[test]
+    fn it_snaps_values_to_the_nearest_setting() {
+        assert_eq!(get_nearest_option(&UI_SCALES, 100), 100);
+        assert_eq!(get_nearest_option(&UI_SCALES, 180), 200);
+        assert_eq!(get_nearest_option(&UI_SCALES, 1000), 200);
+        assert_eq!(get_nearest_option(&WORD_SIZES, 0), 24);
+        assert_eq!(get_nearest_option(&WORD_SIZES, 45), 48);
+    }
+
+    #[test]
+    fn it_scrolls_menus_that_do_not_fit_the_window() {
+        let layout = Layout::default();
+        assert_eq!(layout.get_visible_menu_rows(), 11);
+        assert_eq!(layout.get_menu_scroll(7), 0);
+
+        let large = Layout::new(1200.0, 1000.0, 200, 40);
+        assert_eq!(large.get_visible_menu_rows(), 4);
+        assert_eq!(large.get_menu_scroll(3), 0);
+        assert_eq!(large.get_menu_scroll(7), 4);
+        assert!(large.get_menu_option_y(3) + large.get_menu_text_size() <= 1000.0);
+    }
+
+    #[test]
+    fn it_maps_display_modes_to_ggez_fullscreen_types() {
+        assert_eq!(
+            DisplayMode::Windowed.get_fullscreen_type(),
+            FullscreenType::Windowed
+        );
+        assert_eq!(
+            DisplayMode::Borderless.get_fullscreen_type(),
+            FullscreenType::Desktop
+        );
+        assert_eq!(DisplayMode::Fullscreen.next(), DisplayMode::Windowed);
+    }
+}
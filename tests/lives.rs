@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use ggez::mint::Point2;
+    use typing_tutor::lives::{DamageFeedback, DAMAGE_FLASH_LENGTH, EXPLOSION_LENGTH};
+
+    #[test]
+    fn it_fades_the_damage_feedback_out() {
+        let mut feedback = DamageFeedback::default();
+        feedback.lose_life(2);
+        feedback.explode_word("word", Point2 { x: 1.0, y: 2.0 });
+        assert_eq!(feedback.get_flash_alpha(), 1.0);
+
+        feedback.update(DAMAGE_FLASH_LENGTH / 2.0);
+        assert!((feedback.get_flash_alpha() - 0.5).abs() < 0.0001);
+        assert!(feedback.explosions[0].get_progress() > 0.0);
+
+        feedback.update(EXPLOSION_LENGTH);
+        assert_eq!(feedback.get_flash_alpha(), 0.0);
+        assert!(feedback.explosions.is_empty());
+        assert!(feedback.breaking_life.is_none());
+    }
+
+    #[test]
+    fn it_explodes_the_word_without_a_flash() {
+        let mut feedback = DamageFeedback::default();
+        feedback.explode_word("word", Point2 { x: 1.0, y: 2.0 });
+
+        assert_eq!(feedback.get_flash_alpha(), 0.0);
+        assert!(feedback.breaking_life.is_none());
+    }
+}
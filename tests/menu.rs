@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use typing_tutor::menu::{Menu, MenuMove};
+    use typing_tutor::menu::{Menu, MenuItem, MenuMove, MenuPage, MenuTarget, MenuTree};
 
     #[test]
     fn it_returns_the_selected_option() {
@@ -31,4 +31,124 @@ mod tests {
         menu.navigate(MenuMove::Up);
         assert_eq!(*menu.get_selected_option(), "opt1".to_string());
     }
+
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Command {
+        Play,
+        Toggle,
+        Close,
+    }
+
+    fn create_tree() -> MenuTree<Command> {
+        let mut tree = MenuTree::new(
+            "main",
+            MenuPage::new(vec![
+                MenuItem::command("PLAY".to_string(), Command::Play),
+                MenuItem::submenu("SETTINGS".to_string(), "settings"),
+            ]),
+        );
+        tree.add_page(
+            "settings",
+            MenuPage::new(vec![
+                MenuItem::command("SOUND: OFF".to_string(), Command::Toggle),
+                MenuItem::submenu("MORE".to_string(), "more"),
+            ])
+            .with_back_command(Command::Close),
+        );
+        tree.add_page("more", MenuPage::new(Vec::new()));
+        tree
+    }
+
+    #[test]
+    fn it_returns_the_command_of_the_selected_item() {
+        let mut tree = create_tree();
+
+        assert_eq!(tree.select(), Some(Command::Play));
+        assert_eq!(tree.get_current_id(), "main");
+    }
+
+    #[test]
+    fn it_opens_submenus_and_goes_back_through_them() {
+        let mut tree = create_tree();
+
+        tree.navigate(MenuMove::Down);
+        assert_eq!(tree.select(), None);
+        assert_eq!(tree.get_current_id(), "settings");
+
+        tree.navigate(MenuMove::Down);
+        assert_eq!(tree.select(), None);
+        assert!(tree.is_open("more"));
+
+        // a page without items closes on select
+        assert_eq!(tree.select(), None);
+        assert_eq!(tree.get_current_id(), "settings");
+
+        assert_eq!(tree.back(), Some(Command::Close));
+        assert_eq!(tree.get_current_id(), "main");
+        assert_eq!(tree.back(), None);
+        assert_eq!(tree.get_current_id(), "main");
+    }
+
+    #[test]
+    fn it_goes_back_when_a_back_item_is_selected() {
+        let mut tree = create_tree();
+        tree.get_page_mut("settings").set_items(vec![MenuItem::back("BACK".to_string())]);
+        tree.open("settings");
+
+        assert_eq!(tree.select(), Some(Command::Close));
+        assert_eq!(tree.get_current_id(), "main");
+    }
+
+    #[test]
+    #[should_panic(expected = "no menu page missing")]
+    fn it_does_not_open_pages_that_were_never_added() {
+        let mut tree = create_tree();
+
+        tree.open("missing");
+    }
+
+    #[test]
+    fn it_runs_the_back_command_of_the_last_page() {
+        let mut tree = create_tree();
+        tree.reset("settings");
+
+        assert_eq!(tree.back(), Some(Command::Close));
+        assert_eq!(tree.get_current_id(), "settings");
+    }
+
+    #[test]
+    fn it_relabels_items_by_their_target() {
+        let mut tree = create_tree();
+
+        tree.set_label(
+            &MenuTarget::Command(Command::Toggle),
+            "SOUND: ON".to_string(),
+        );
+        tree.set_label(&MenuTarget::Submenu("settings"), "OPTIONS".to_string());
+
+        assert_eq!(tree.get_page("settings").menu.options[0], "SOUND: ON");
+        assert_eq!(tree.get_page("main").menu.options[1], "OPTIONS");
+    }
+
+    #[test]
+    fn it_keeps_the_selection_when_items_are_replaced() {
+        let mut page = MenuPage::new(vec![
+            MenuItem::command("A".to_string(), Command::Play),
+            MenuItem::command("B".to_string(), Command::Toggle),
+            MenuItem::command("C".to_string(), Command::Close),
+        ]);
+        page.menu.navigate(MenuMove::Down);
+
+        page.set_items(vec![
+            MenuItem::command("D".to_string(), Command::Play),
+            MenuItem::command("E".to_string(), Command::Toggle),
+        ]);
+        assert_eq!(*page.menu.get_selected_option(), "E".to_string());
+
+        page.set_items(vec![MenuItem::command("F".to_string(), Command::Play)]);
+        assert_eq!(
+            page.get_selected_target(),
+            Some(&MenuTarget::Command(Command::Play))
+        );
+    }
 }
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use ggez::mint::Point2;
+    use typing_tutor::motion::{Motion, MotionPattern};
+
+    fn create_motion(pattern: MotionPattern, horizontal_speed: f32, anchor_x: f32) -> Motion {
+        Motion {
+            pattern,
+            horizontal_speed,
+            anchor_x,
+            ..Motion::default()
+        }
+    }
+
+    #[test]
+    fn it_moves_linear_words_straight_down() {
+        let mut motion = Motion::default();
+        let mut position = Point2 { x: 10.0, y: 0.0 };
+
+        motion.update(&mut position, 5.0, 0.1, 100.0);
+
+        assert_eq!(position.x, 10.0);
+        assert_eq!(position.y, 5.0);
+    }
+
+    #[test]
+    fn it_bounces_diagonal_words_off_the_walls() {
+        let mut motion = create_motion(MotionPattern::DiagonalBounce, 100.0, 0.0);
+        let mut position = Point2 { x: 90.0, y: 0.0 };
+
+        motion.update(&mut position, 1.0, 0.2, 100.0);
+        assert_eq!(position.x, 100.0);
+        assert_eq!(motion.horizontal_speed, -100.0);
+
+        motion.update(&mut position, 1.0, 0.2, 100.0);
+        assert_eq!(position.x, 80.0);
+    }
+
+    #[test]
+    fn it_keeps_sine_drifting_words_inside_the_screen() {
+        let mut motion = create_motion(MotionPattern::SineDrift, 0.0, 10.0);
+        let mut position = Point2 { x: 10.0, y: 0.0 };
+
+        for _ in 0..100 {
+            motion.update(&mut position, 1.0, 0.05, 100.0);
+            assert!(position.x >= 0.0 && position.x <= 100.0);
+        }
+    }
+
+    #[test]
+    fn it_speeds_up_accelerating_words_over_time() {
+        let mut motion = create_motion(MotionPattern::Accelerating, 0.0, 0.0);
+        let mut position = Point2 { x: 0.0, y: 0.0 };
+
+        motion.update(&mut position, 10.0, 1.0, 100.0);
+        let first_step = position.y;
+        motion.update(&mut position, 10.0, 1.0, 100.0);
+
+        assert!(position.y - first_step > first_step);
+    }
+
+    #[test]
+    fn it_slides_side_entry_words_to_their_anchor() {
+        let mut motion = create_motion(MotionPattern::SideEntry, 50.0, 60.0);
+        let mut position = Point2 { x: 0.0, y: 0.0 };
+
+        motion.update(&mut position, 1.0, 1.0, 100.0);
+        assert_eq!(position.x, 50.0);
+
+        motion.update(&mut position, 1.0, 1.0, 100.0);
+        assert_eq!(position.x, 60.0);
+        motion.update(&mut position, 1.0, 1.0, 100.0);
+        assert_eq!(position.x, 60.0);
+    }
+
+    #[test]
+    fn it_spawns_side_entry_words_on_the_edge() {
+        let (motion, spawn_x) =
+            Motion::new_random(&[MotionPattern::SideEntry], 100.0, &mut rand::thread_rng());
+
+        assert_eq!(motion.pattern, MotionPattern::SideEntry);
+        assert!(spawn_x == 0.0 || spawn_x == 100.0);
+        assert!(motion.anchor_x >= 0.0 && motion.anchor_x <= 100.0);
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::preset::{Difficulty, Preset, PresetField, PresetSettings};
+    use typing_tutor::rules::GameRules;
+
+    #[test]
+    fn it_uses_the_default_rules_for_the_normal_preset() {
+        let rules = Preset::get_builtin(Difficulty::Normal).get_rules();
+
+        assert_eq!(rules, GameRules::default());
+    }
+
+    #[test]
+    fn it_makes_harder_presets_faster() {
+        let easy = Preset::get_builtin(Difficulty::Easy);
+        let normal = Preset::get_builtin(Difficulty::Normal);
+        let hard = Preset::get_builtin(Difficulty::Hard);
+        let insane = Preset::get_builtin(Difficulty::Insane);
+
+        assert!(easy.starting_speed < normal.starting_speed);
+        assert!(normal.starting_speed < hard.starting_speed);
+        assert!(hard.starting_speed < insane.starting_speed);
+        assert!(easy.starting_lives > insane.starting_lives);
+        assert!(easy.max_lives > insane.max_lives);
+    }
+
+    #[test]
+    fn it_never_starts_with_more_lives_than_the_maximum() {
+        let preset = Preset {
+            starting_lives: 9,
+            max_lives: 4,
+            ..Preset::default()
+        };
+
+        assert_eq!(preset.get_rules().starting_lives, 4);
+        assert_eq!(preset.get_rules().max_lives, 4);
+    }
+
+    #[test]
+    fn it_cycles_preset_fields_and_wraps_around() {
+        let mut preset = Preset::default();
+
+        PresetField::StartingLives.cycle(&mut preset);
+        assert_eq!(preset.starting_lives, 4);
+
+        preset.starting_lives = 9;
+        PresetField::StartingLives.cycle(&mut preset);
+        assert_eq!(preset.starting_lives, 0);
+
+        PresetField::SpawnInterval.cycle(&mut preset);
+        assert!((preset.spawn_interval - 1.1).abs() < 0.0001);
+
+        preset.spawn_interval = 2.0;
+        PresetField::SpawnInterval.cycle(&mut preset);
+        assert!((preset.spawn_interval - 0.4).abs() < 0.0001);
+    }
+
+    #[test]
+    fn it_labels_the_preset_fields_with_the_preset_values() {
+        let preset = Preset::default();
+
+        assert_eq!(PresetField::StartingLives.get_label(&preset), "LIVES: 3");
+        assert_eq!(PresetField::MaxLives.get_label(&preset), "MAX LIVES: 5");
+        assert_eq!(PresetField::StartingSpeed.get_label(&preset), "SPEED: 50");
+    }
+
+    #[test]
+    fn it_returns_the_custom_preset_only_when_custom_is_selected() {
+        let mut settings = PresetSettings::default();
+        settings.custom_preset.starting_lives = 5;
+
+        assert_eq!(
+            settings.get_preset(),
+            Preset::get_builtin(Difficulty::Normal)
+        );
+
+        settings.difficulty = Difficulty::Custom;
+        assert_eq!(settings.get_preset().starting_lives, 5);
+    }
+
+    #[test]
+    fn it_falls_back_to_the_defaults_for_a_broken_file() {
+        let path = std::env::temp_dir().join(format!("presets-{}.toml", std::process::id()));
+        std::fs::write(&path, "difficulty = ").unwrap();
+
+        let settings = PresetSettings::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(settings, PresetSettings::default());
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::game::GameMode;
+    use typing_tutor::preset::{Difficulty, Preset};
+    use typing_tutor::progression::{
+        calculate_xp, get_unlock_level, sanitize_profile_name, Profile, Unlock,
+        DEFAULT_PROFILE_NAME,
+    };
+    use typing_tutor::results::{GameSummary, ResultsHistory};
+
+    fn create_summary(mode: GameMode, difficulty: Difficulty) -> GameSummary {
+        GameSummary {
+            mode: mode.get_label(),
+            level: None,
+            difficulty,
+            preset: Preset::get_builtin(difficulty),
+            score: 100,
+            completed_words: 10,
+            best_streak: 0,
+            misremembered_words: Vec::new(),
+            xp: 0,
+            word_scores: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn it_levels_up_with_growing_xp_requirements() {
+        let mut profile = Profile::default();
+        assert_eq!(profile.get_level_progress(), (1, 0, 100));
+
+        assert_eq!(profile.add_xp(150), 1);
+        assert_eq!(profile.get_level_progress(), (2, 50, 200));
+
+        assert_eq!(profile.add_xp(250), 1);
+        assert_eq!(profile.get_level_progress(), (3, 100, 300));
+    }
+
+    #[test]
+    fn it_weighs_xp_by_accuracy_and_mode() {
+        assert_eq!(calculate_xp(1000, 1.0, GameMode::Endless), 100);
+        assert_eq!(calculate_xp(1000, 0.5, GameMode::Endless), 50);
+        assert_eq!(calculate_xp(1000, 1.0, GameMode::Blind), 150);
+    }
+
+    #[test]
+    fn it_unlocks_content_at_its_level() {
+        let mut profile = Profile::default();
+        let insane = Unlock::Difficulty(Difficulty::Insane);
+        assert!(profile.is_unlocked(Unlock::Difficulty(Difficulty::Hard)));
+        assert!(!profile.is_unlocked(insane));
+
+        while profile.get_level() < get_unlock_level(insane) {
+            profile.add_xp(100);
+        }
+        assert!(profile.is_unlocked(insane));
+    }
+
+    #[test]
+    fn it_keeps_modes_and_difficulties_played_before_levels_unlocked() {
+        let mut history = ResultsHistory::default();
+        history.add(create_summary(GameMode::Blind, Difficulty::Normal));
+        let mut profile = Profile::default();
+
+        profile.keep_used_content(&history);
+
+        assert_eq!(profile.get_level(), 1);
+        assert!(profile.is_unlocked(Unlock::Mode(GameMode::Blind)));
+        assert!(!profile.is_unlocked(Unlock::Difficulty(Difficulty::Insane)));
+
+        history.add(create_summary(GameMode::Endless, Difficulty::Insane));
+        profile.keep_used_content(&history);
+
+        assert!(profile.is_unlocked(Unlock::Difficulty(Difficulty::Insane)));
+    }
+
+    #[test]
+    fn it_keeps_content_played_in_a_saved_history_unlocked() {
+        let path = std::env::temp_dir().join(format!("played-{}.toml", std::process::id()));
+        let mut history = ResultsHistory::load(&path);
+        history.add(create_summary(GameMode::Blind, Difficulty::Insane));
+        let mut profile = Profile::default();
+
+        profile.keep_used_content(&ResultsHistory::load(&path));
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(profile.is_unlocked(Unlock::Mode(GameMode::Blind)));
+        assert!(profile.is_unlocked(Unlock::Difficulty(Difficulty::Insane)));
+    }
+
+    #[test]
+    fn it_keeps_profile_names_inside_the_profiles_directory() {
+        assert_eq!(sanitize_profile_name("alice"), "alice");
+        assert_eq!(sanitize_profile_name("../../etc/passwd"), "etcpasswd");
+        assert_eq!(sanitize_profile_name("C:\\Users\\bob"), "CUsersbob");
+        assert_eq!(sanitize_profile_name(".."), DEFAULT_PROFILE_NAME);
+        assert_eq!(sanitize_profile_name(" / "), DEFAULT_PROFILE_NAME);
+        assert_eq!(sanitize_profile_name(""), DEFAULT_PROFILE_NAME);
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::preset::{Difficulty, Preset};
+    use typing_tutor::results::{GameSummary, ResultsHistory};
+
+    fn create_summary(difficulty: Difficulty, score: u32) -> GameSummary {
+        GameSummary {
+            mode: String::from("ENDLESS"),
+            level: None,
+            difficulty,
+            preset: Preset::get_builtin(difficulty),
+            score,
+            completed_words: score / 10,
+            best_streak: 0,
+            misremembered_words: Vec::new(),
+            xp: 0,
+            word_scores: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn it_returns_no_best_score_without_results() {
+        let history = ResultsHistory::default();
+
+        assert_eq!(
+            history.get_best_score(&create_summary(Difficulty::Normal, 10)),
+            None
+        );
+    }
+
+    #[test]
+    fn it_compares_scores_only_within_the_same_preset() {
+        let mut history = ResultsHistory::default();
+        history.add(create_summary(Difficulty::Normal, 100));
+        history.add(create_summary(Difficulty::Normal, 300));
+        history.add(create_summary(Difficulty::Hard, 500));
+
+        assert_eq!(
+            history.get_best_score(&create_summary(Difficulty::Normal, 0)),
+            Some(300)
+        );
+        assert_eq!(
+            history.get_best_score(&create_summary(Difficulty::Hard, 0)),
+            Some(500)
+        );
+        assert_eq!(
+            history.get_best_score(&create_summary(Difficulty::Easy, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn it_separates_custom_presets_with_different_values() {
+        let mut history = ResultsHistory::default();
+        let mut summary = create_summary(Difficulty::Custom, 200);
+        history.add(summary.clone());
+
+        summary.preset.starting_lives += 1;
+        assert_eq!(history.get_best_score(&summary), None);
+    }
+
+    #[test]
+    fn it_compares_campaign_results_by_level_regardless_of_the_preset() {
+        let mut history = ResultsHistory::default();
+        let mut summary = create_summary(Difficulty::Easy, 400);
+        summary.mode = String::from("CAMPAIGN FIRST STEPS");
+        summary.level = Some(String::from("First steps"));
+        history.add(summary.clone());
+
+        let mut other_preset = create_summary(Difficulty::Hard, 0);
+        other_preset.mode = summary.mode.clone();
+        other_preset.level = summary.level.clone();
+        assert_eq!(history.get_best_score(&other_preset), Some(400));
+
+        other_preset.level = Some(String::from("Rush"));
+        assert_eq!(history.get_best_score(&other_preset), None);
+    }
+
+    #[test]
+    fn it_loads_the_saved_results() {
+        let path = std::env::temp_dir().join(format!("results-{}.toml", std::process::id()));
+        let mut history = ResultsHistory::load(&path);
+        let mut summary = create_summary(Difficulty::Hard, 250);
+        summary.misremembered_words = vec![String::from("climate")];
+        history.add(summary.clone());
+        history.add(create_summary(Difficulty::Custom, 120));
+
+        let loaded = ResultsHistory::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.results, history.results);
+        assert_eq!(loaded.get_best_score(&summary), Some(250));
+    }
+}
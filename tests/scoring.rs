@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::scoring::{get_letter_difficulty, WordScore};
+
+    #[test]
+    fn it_scores_longer_words_higher() {
+        let short = WordScore::new("cat", 10, 10.0, 0.0);
+        let long = WordScore::new("establishment", 10, 10.0, 0.0);
+
+        assert_eq!(short.length, 6);
+        assert_eq!(long.length, 26);
+        assert!(long.total > short.total);
+    }
+
+    #[test]
+    fn it_weighs_rare_letters() {
+        assert_eq!(get_letter_difficulty("e"), 0);
+        assert_eq!(get_letter_difficulty("Q"), 3);
+        assert_eq!(get_letter_difficulty("ö"), 2);
+        assert_eq!(WordScore::new("jazz", 10, 10.0, 0.0).letters, 9);
+    }
+
+    #[test]
+    fn it_rewards_fast_reactions_and_words_close_to_the_bottom() {
+        let fresh = WordScore::new("hello", 10, 0.0, 0.0);
+        let slow = WordScore::new("hello", 10, 10.0, 0.0);
+        let low = WordScore::new("hello", 10, 10.0, 1.0);
+
+        assert_eq!(fresh.reaction, 5);
+        assert_eq!(slow.reaction, 0);
+        assert_eq!(slow.danger, 0);
+        assert_eq!(low.danger, 5);
+        assert_eq!(low.total, 15);
+    }
+
+    #[test]
+    fn it_applies_multipliers_to_the_total() {
+        let mut score = WordScore::new("hello", 10, 10.0, 0.0);
+        score.multiply(2);
+        score.multiply(3);
+
+        assert_eq!(score.total, 60);
+        assert_eq!(score.get_label(), "HELLO 10+0+0+0 X6 = 60");
+    }
+
+    #[test]
+    fn it_scales_every_part_so_the_breakdown_adds_up() {
+        let mut score = WordScore::new("jazz", 10, 0.0, 1.0);
+        score.scale(0.5);
+
+        assert_eq!(score.length, 4);
+        assert_eq!(score.letters, 5);
+        assert_eq!(score.reaction, 3);
+        assert_eq!(score.danger, 3);
+        assert_eq!(
+            score.total,
+            score.length + score.letters + score.reaction + score.danger
+        );
+        assert_eq!(score.get_label(), "JAZZ 4+5+3+3 = 15");
+    }
+}
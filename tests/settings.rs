@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::settings::{Settings, SETTINGS_VERSION};
+
+    #[test]
+    fn it_reads_the_saved_settings() {
+        let settings = Settings::from_toml_str(
+            r#"
+            version = 2
+            words_font = "Creepster"
+            theme = "green"
+            spectator = true
+            hide_typed_letters = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.words_font, "Creepster");
+        assert_eq!(settings.theme, "green");
+        assert!(settings.spectator);
+        assert!(settings.hide_typed_letters);
+    }
+
+    #[test]
+    fn it_fills_in_missing_fields_and_ignores_unknown_ones() {
+        let settings = Settings::from_toml_str(
+            r#"
+            words_font = "Creepster"
+            volume = 11
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            settings,
+            Settings {
+                words_font: String::from("Creepster"),
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn it_upgrades_unversioned_files_and_keeps_newer_versions() {
+        let old = Settings::from_toml_str("words_font = \"Creepster\"").unwrap();
+        assert_eq!(old.version, SETTINGS_VERSION);
+
+        let newer = Settings::from_toml_str("version = 99\ntheme = \"green\"").unwrap();
+        assert_eq!(newer.version, 99);
+        assert_eq!(newer.theme, "green");
+    }
+
+    #[test]
+    fn it_turns_the_old_background_colour_into_a_theme() {
+        let settings = Settings::from_toml_str("version = 1\nbg_color = \"CRIMSON\"").unwrap();
+
+        assert_eq!(settings.theme, "crimson");
+        assert_eq!(settings.version, SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn it_snaps_the_ui_scale_and_word_size_to_known_values() {
+        let settings = Settings::from_toml_str(
+            r#"
+            ui_scale = 180
+            word_size = 1000
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.ui_scale, 200);
+        assert_eq!(settings.word_size, 80);
+    }
+
+    #[test]
+    fn it_rejects_settings_that_are_not_toml() {
+        assert!(Settings::from_toml_str("words_font = ").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use std::net::TcpStream;
+    use std::time::Duration;
+
+    use ggez::mint::Point2;
+    use tungstenite::{Message, WebSocket};
+    use typing_tutor::spectator::{
+        GameSnapshot, SpectatorEvent, SpectatorServer, WordSnapshot, MAX_SPECTATORS,
+    };
+    use typing_tutor::word::{Word, WordEffect};
+
+    #[test]
+    fn it_serializes_events_with_a_type_tag() {
+        let event = SpectatorEvent::GameStarted;
+        assert_eq!(event.to_json(), r#"{"type":"game_started"}"#);
+
+        let event = SpectatorEvent::EffectActivated {
+            effect: WordEffect::SlowDown,
+        };
+        assert_eq!(
+            event.to_json(),
+            r#"{"type":"effect_activated","effect":"slow_down"}"#
+        );
+
+        let event = SpectatorEvent::LifeLost { life_points: 2 };
+        assert_eq!(event.to_json(), r#"{"type":"life_lost","life_points":2}"#);
+    }
+
+    #[test]
+    fn it_serializes_snapshots_with_the_game_state() {
+        let mut word = Word::new("val", Point2 { x: 1.0, y: 2.0 }, 1);
+        word.effect = None;
+
+        let event = SpectatorEvent::Snapshot(GameSnapshot {
+            score: 10,
+            life_points: 1,
+            game_speed: 55,
+            words: Vec::from([WordSnapshot::from(&word)]),
+        });
+
+        assert_eq!(
+            event.to_json(),
+            r#"{"type":"snapshot","score":10,"life_points":1,"game_speed":55,"words":[{"value":"val","progress_index":1,"x":1.0,"y":2.0,"effect":null}]}"#
+        );
+    }
+
+    fn connect(server: &SpectatorServer) -> WebSocket<TcpStream> {
+        let stream = TcpStream::connect(("127.0.0.1", server.port())).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_millis(100)))
+            .unwrap();
+        let url = format!("ws://127.0.0.1:{}", server.port());
+        tungstenite::client(url, stream).unwrap().0
+    }
+
+    // the client is registered shortly after its handshake, events sent before that are missed
+    fn broadcast_until_received(
+        server: &SpectatorServer,
+        client: &mut WebSocket<TcpStream>,
+        event: &SpectatorEvent,
+    ) -> Message {
+        for _ in 0..50 {
+            server.broadcast(event);
+            if let Ok(message) = client.read_message() {
+                return message;
+            }
+        }
+        panic!("no message was received");
+    }
+
+    #[test]
+    fn it_broadcasts_events_to_connected_clients() {
+        let server = SpectatorServer::start(0).unwrap();
+        let mut client = connect(&server);
+
+        let message = broadcast_until_received(
+            &server,
+            &mut client,
+            &SpectatorEvent::GameOver { score: 30 },
+        );
+        assert_eq!(
+            message,
+            Message::Text(String::from(r#"{"type":"game_over","score":30}"#))
+        );
+    }
+
+    #[test]
+    fn it_keeps_broadcasting_while_a_client_stalls_the_handshake() {
+        let server = SpectatorServer::start(0).unwrap();
+        let _silent_client = TcpStream::connect(("127.0.0.1", server.port())).unwrap();
+        let mut client = connect(&server);
+
+        let message = broadcast_until_received(&server, &mut client, &SpectatorEvent::GameStarted);
+        assert_eq!(
+            message,
+            Message::Text(String::from(r#"{"type":"game_started"}"#))
+        );
+    }
+
+    #[test]
+    fn it_closes_connections_over_the_spectator_limit() {
+        let server = SpectatorServer::start(0).unwrap();
+        let _clients: Vec<WebSocket<TcpStream>> =
+            (0..MAX_SPECTATORS).map(|_| connect(&server)).collect();
+
+        let stream = TcpStream::connect(("127.0.0.1", server.port())).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(1)))
+            .unwrap();
+        let url = format!("ws://127.0.0.1:{}", server.port());
+        assert!(tungstenite::client(url, stream).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::streak::{Streak, STREAK_BREAK_ANIMATION_LENGTH};
+
+    #[test]
+    fn it_raises_the_multiplier_at_each_threshold() {
+        let mut streak = Streak::default();
+        assert_eq!(streak.get_multiplier(), 1);
+
+        let expected = [(10, 2), (20, 3), (30, 4), (45, 4)];
+        for (words, multiplier) in expected {
+            while streak.current < words {
+                streak.register_completed_word();
+            }
+            assert_eq!(streak.get_multiplier(), multiplier);
+        }
+    }
+
+    #[test]
+    fn it_remembers_the_best_streak_after_breaking() {
+        let mut streak = Streak::default();
+        for _ in 0..5 {
+            streak.register_completed_word();
+        }
+
+        streak.break_streak();
+        streak.register_completed_word();
+
+        assert_eq!(streak.current, 1);
+        assert_eq!(streak.best, 5);
+        assert_eq!(streak.get_multiplier(), 1);
+    }
+
+    #[test]
+    fn it_shows_the_broken_streak_until_the_animation_ends() {
+        let mut streak = Streak::default();
+        streak.break_streak();
+        assert!(streak.broken.is_none());
+
+        streak.register_completed_word();
+        streak.break_streak();
+        assert_eq!(streak.broken, Some((1, STREAK_BREAK_ANIMATION_LENGTH)));
+
+        streak.update_break_animation(STREAK_BREAK_ANIMATION_LENGTH);
+        assert!(streak.broken.is_none());
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use ggez::graphics::Color;
+    use typing_tutor::effects::get_default_effect_table;
+    use typing_tutor::theme::{get_bundled_themes, Theme, DEFAULT_THEME_NAME};
+    use typing_tutor::word::WordEffect;
+
+    #[test]
+    fn it_parses_every_bundled_theme() {
+        let themes = get_bundled_themes();
+
+        assert_eq!(themes.len(), 6);
+        assert!(themes.contains(&Theme::default()));
+        assert_eq!(
+            themes
+                .iter()
+                .filter(|theme| theme.name == DEFAULT_THEME_NAME)
+                .count(),
+            1
+        );
+        assert!(themes.iter().any(|theme| theme.is_light));
+        assert!(themes.iter().any(|theme| !theme.is_light));
+    }
+
+    #[test]
+    fn it_uses_the_default_colours_for_missing_fields() {
+        let theme = Theme::from_toml_str(
+            r#"
+            name = "gray"
+            background = [0.3, 0.3, 0.3, 1.0]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(theme.name, "gray");
+        assert_eq!(theme.background, [0.3, 0.3, 0.3, 1.0]);
+        assert_eq!(theme.text, Theme::default().text);
+        assert_eq!(theme.get_label(), "GRAY (DARK)");
+    }
+
+    #[test]
+    fn it_overrides_effect_colours() {
+        let theme = Theme::from_toml_str(
+            r#"
+            name = "light"
+            is_light = true
+
+            [effect_colors]
+            slow_down = [0.0, 0.0, 0.5, 1.0]
+            "#,
+        )
+        .unwrap();
+        let effect_table = get_default_effect_table();
+
+        assert_eq!(
+            theme.get_effect_color(WordEffect::SlowDown, effect_table),
+            Color::new(0.0, 0.0, 0.5, 1.0)
+        );
+        assert_eq!(
+            theme.get_effect_color(WordEffect::Bomb, effect_table),
+            effect_table.get_color(Some(WordEffect::Bomb))
+        );
+        assert_eq!(theme.get_label(), "LIGHT (LIGHT)");
+    }
+
+    #[test]
+    fn it_gives_every_effect_its_own_colour_in_every_bundled_theme() {
+        let effect_table = get_default_effect_table();
+
+        for theme in get_bundled_themes() {
+            for (idx, effect) in WordEffect::ALL.iter().enumerate() {
+                let color = theme.get_effect_color(*effect, effect_table);
+                assert_ne!(color, theme.get_word_color(None, effect_table));
+                for other in &WordEffect::ALL[idx + 1..] {
+                    assert_ne!(
+                        color,
+                        theme.get_effect_color(*other, effect_table),
+                        "{:?} and {:?} look the same in {}",
+                        effect,
+                        other,
+                        theme.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_names_a_theme_file_without_a_name_after_the_file() {
+        let contents = "background = [0.0, 0.2, 0.4, 1.0]";
+
+        let theme = Theme::from_theme_file(contents, Path::new("/themes/ocean.toml")).unwrap();
+        assert_eq!(theme.name, "ocean");
+        assert_eq!(theme.background, [0.0, 0.2, 0.4, 1.0]);
+
+        let contents = "name = \"deep sea\"";
+        let theme = Theme::from_theme_file(contents, Path::new("/themes/ocean.toml")).unwrap();
+        assert_eq!(theme.name, "deep sea");
+    }
+
+    #[test]
+    fn it_rejects_invalid_theme_files() {
+        assert!(Theme::from_toml_str("background = \"blue\"").is_err());
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use typing_tutor::wave::{WaveState, INTERMISSION_LENGTH, WORDS_PER_WAVE};
+
+    #[test]
+    fn it_initializes_the_first_wave() {
+        let wave = WaveState::new();
+
+        assert_eq!(wave.wave_number, 1);
+        assert_eq!(wave.words_left_to_spawn, WORDS_PER_WAVE);
+        assert!(!wave.is_in_intermission());
+        assert!(wave.can_spawn_word());
+    }
+
+    #[test]
+    fn it_stops_spawning_when_the_wave_words_are_spawned() {
+        let mut wave = WaveState::new();
+
+        for _ in 0..WORDS_PER_WAVE {
+            wave.register_spawned_word();
+        }
+
+        assert_eq!(wave.words_left_to_spawn, 0);
+        assert!(!wave.can_spawn_word());
+        assert!(!wave.is_cleared(1));
+        assert!(wave.is_cleared(0));
+    }
+
+    #[test]
+    fn it_runs_the_intermission_and_starts_the_next_wave() {
+        let mut wave = WaveState::new();
+
+        wave.start_intermission();
+        assert!(wave.is_in_intermission());
+        assert!(!wave.can_spawn_word());
+        assert_eq!(wave.intermission_time_left, Some(INTERMISSION_LENGTH));
+
+        assert!(!wave.update_intermission(1.0));
+        assert_eq!(wave.intermission_time_left, Some(INTERMISSION_LENGTH - 1.0));
+        assert!(wave.update_intermission(INTERMISSION_LENGTH));
+
+        wave.start_next_wave();
+        assert_eq!(wave.wave_number, 2);
+        assert_eq!(wave.words_left_to_spawn, WORDS_PER_WAVE);
+        assert!(!wave.is_in_intermission());
+    }
+}
@@ -1,30 +1,22 @@
 #[cfg(test)]
 mod tests {
-    use ggez::{graphics::Color, mint::Point2};
-    use typing_tutor::word::{Word, WordEffect};
+    use ggez::mint::Point2;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use typing_tutor::word::{HazardTrigger, Word, WordEffect, WordVisibility};
 
     #[test]
-    fn it_returns_the_correct_color_when_the_word_has_no_effect() {
-        let mut word = Word::new("val", Point2 { x: 1.0, y: 1.0 }, 0);
-        word.effect = None;
-
-        assert_eq!(word.get_color(), Color::WHITE);
-    }
-
-    #[test]
-    fn it_returns_the_correct_color_when_the_word_has_an_effect() {
-        let mut word = Word::new("val", Point2 { x: 1.0, y: 1.0 }, 0);
-        word.effect = Some(WordEffect::AddLife);
-
-        assert_eq!(word.get_color(), Color::GREEN);
-
-        word.effect = Some(WordEffect::SlowDown);
-
-        assert_eq!(word.get_color(), Color::new(0.06, 0.9, 0.92, 0.65));
-
-        word.effect = Some(WordEffect::SpawnOnlyShortWords);
-
-        assert_eq!(word.get_color(), Color::YELLOW);
+    fn it_rolls_the_same_effects_with_the_same_seed() {
+        let mut first_rng = StdRng::seed_from_u64(7);
+        let mut second_rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..50 {
+            let position = Point2 { x: 1.0, y: 1.0 };
+            assert_eq!(
+                Word::with_rng("val", position, 0, &mut first_rng).effect,
+                Word::with_rng("val", position, 0, &mut second_rng).effect
+            );
+        }
     }
 
     #[test]
@@ -72,4 +64,77 @@ mod tests {
         word.update_position(1.0);
         assert_eq!(word.position.y, 2.0);
     }
+
+    #[test]
+    fn it_masks_the_word_once_the_reveal_time_runs_out() {
+        let mut word = Word::new("val", Point2 { x: 1.0, y: 1.0 }, 0);
+        word.visibility = WordVisibility::Revealed { time_left: 1.0 };
+
+        word.update_visibility(0.5);
+        assert!(!word.is_masked());
+
+        word.update_visibility(0.6);
+        assert!(word.is_masked());
+        assert_eq!(word.visibility, WordVisibility::Masked);
+    }
+
+    #[test]
+    fn it_returns_the_masked_value_depending_on_the_hidden_letters() {
+        let mut word = Word::new("climate", Point2 { x: 1.0, y: 1.0 }, 0);
+        word.progress_index = 2;
+
+        assert_eq!(word.get_masked_value(false), "cl*****");
+        assert_eq!(word.get_masked_value(true), "*******");
+    }
+
+    #[test]
+    fn it_counts_mistakes_towards_the_accuracy() {
+        let mut word = Word::new("val", Point2 { x: 1.0, y: 1.0 }, 0);
+        assert_eq!(word.get_accuracy(), 1.0);
+
+        word.handle_typed_letter('v');
+        word.handle_typed_letter('x');
+        assert_eq!(word.mistakes, 1);
+        assert_eq!(word.get_accuracy(), 0.75);
+    }
+
+    #[test]
+    fn it_scrambles_only_the_letters_left_to_type() {
+        let mut word = Word::new("climate", Point2 { x: 1.0, y: 1.0 }, 3);
+
+        word.scramble(&mut rand::thread_rng());
+
+        assert!(word.value.starts_with("cli"));
+        let mut letters: Vec<char> = word.value.chars().skip(3).collect();
+        letters.sort();
+        assert_eq!(letters, ['a', 'e', 'm', 't']);
+    }
+
+    #[test]
+    fn it_splits_a_word_into_halves() {
+        let word = Word::new("climate", Point2 { x: 1.0, y: 1.0 }, 0);
+        assert_eq!(
+            word.get_split_values(),
+            Some((String::from("cli"), String::from("mate")))
+        );
+
+        let word = Word::new("a", Point2 { x: 1.0, y: 1.0 }, 0);
+        assert!(word.get_split_values().is_none());
+    }
+
+    #[test]
+    fn it_tells_hazards_apart_from_power_ups() {
+        assert!(WordEffect::POWER_UPS
+            .iter()
+            .all(|effect| !effect.is_hazard()));
+        assert!(WordEffect::HAZARDS.iter().all(|effect| effect.is_hazard()));
+        assert_eq!(
+            WordEffect::Fog.get_hazard_trigger(),
+            Some(HazardTrigger::Missed)
+        );
+        assert_eq!(
+            WordEffect::Split.get_hazard_trigger(),
+            Some(HazardTrigger::Completed)
+        );
+    }
 }